@@ -1,21 +1,20 @@
-use crate::alsw::{BASE32HEX_ALSW_CHECK_X2, BASE32_ALSW_CHECK_X2};
-use crate::decode::{decode_bits, decode_extra};
-use crate::decode::{BASE32HEX_TABLE, BASE32_TABLE};
-use crate::Error;
-use crate::Kind;
+use crate::decode::DecodeLut;
+use crate::decode::{decode_bits, decode_extra, decode_lut_of, decoding_table_of, for_each_without_hyphens, locate};
+use crate::lookup::decode_values;
+use crate::{Base32, Error};
 
-use vsimd::alsw::AlswLut;
+use vsimd::mask::u8x32_highbit_any;
 use vsimd::vector::V256;
 use vsimd::SIMD256;
 
+use core::ops::Not;
+
 use core::ptr::null_mut;
 
 #[inline(always)]
-pub(crate) unsafe fn check_fallback(mut src: *const u8, mut len: usize, kind: Kind) -> Result<(), Error> {
-    let table = match kind {
-        Kind::Base32 => BASE32_TABLE.as_ptr(),
-        Kind::Base32Hex => BASE32HEX_TABLE.as_ptr(),
-    };
+pub(crate) unsafe fn check_fallback(mut src: *const u8, mut len: usize, base32: &Base32) -> Result<(), Error> {
+    let mut custom = None;
+    let table = decoding_table_of(base32, &mut custom).as_ptr();
 
     let end = src.add(len / 8 * 8);
     while src < end {
@@ -29,27 +28,50 @@ pub(crate) unsafe fn check_fallback(mut src: *const u8, mut len: usize, kind: Ki
 }
 
 #[inline(always)]
-pub(crate) unsafe fn check_simd<S: SIMD256>(s: S, mut src: *const u8, mut len: usize, kind: Kind) -> Result<(), Error> {
-    let check_lut = match kind {
-        Kind::Base32 => BASE32_ALSW_CHECK_X2,
-        Kind::Base32Hex => BASE32HEX_ALSW_CHECK_X2,
-    };
+pub(crate) unsafe fn check_simd<S: SIMD256>(
+    s: S,
+    mut src: *const u8,
+    mut len: usize,
+    base32: &Base32,
+) -> Result<(), Error> {
+    let mut custom = None;
+    let lut = decode_lut_of(base32, &mut custom);
 
     let end = src.add(len / 32 * 32);
     while src < end {
         let x = s.v256_load_unaligned(src);
 
-        let is_valid = check_ascii32(s, x, check_lut);
+        let is_valid = check_ascii32(s, x, lut);
         ensure!(is_valid);
 
         src = src.add(32);
     }
     len %= 32;
 
-    check_fallback(src, len, kind)
+    check_fallback(src, len, base32)
 }
 
 #[inline(always)]
-fn check_ascii32<S: SIMD256>(s: S, x: V256, check: AlswLut<V256>) -> bool {
-    vsimd::alsw::check_ascii_xn(s, x, check)
+fn check_ascii32<S: SIMD256>(s: S, x: V256, lut: DecodeLut<'_>) -> bool {
+    match lut {
+        DecodeLut::Alsw(check, _) => vsimd::alsw::check_ascii_xn(s, x, check),
+        DecodeLut::AlswUppercase(check, _) => {
+            let x = vsimd::ascii::to_ascii_uppercase(s, x);
+            vsimd::alsw::check_ascii_xn(s, x, check)
+        }
+        DecodeLut::Lookup(lut) => u8x32_highbit_any(s, decode_values(s, x, lut)).not(),
+    }
+}
+
+/// Checks `src`, which has `n` data characters.
+#[inline]
+pub(crate) fn check_data(src: &[u8], n: usize, base32: &Base32) -> Result<(), Error> {
+    let ans = if n < src.len() && base32.kind.ignores_hyphens() {
+        for_each_without_hyphens(src, |chunk| unsafe {
+            crate::multiversion::check::auto(chunk.as_ptr(), chunk.len(), base32)
+        })
+    } else {
+        unsafe { crate::multiversion::check::auto(src.as_ptr(), n, base32) }
+    };
+    ans.map_err(|_| locate(src, n, base32))
 }
@@ -0,0 +1,158 @@
+//! Check symbols of Crockford's base32.
+//!
+//! A check symbol is appended to a [`CROCKFORD`] string to detect transcription errors.
+//! Its value is the value of the data symbols as a base-32 number, modulo 37.
+//! The values from 32 to 36 use the extra symbols `*`, `~`, `$`, `=` and `U`.
+//!
+//! See <https://www.crockford.com/base32.html>.
+//!
+//! # Examples
+//!
+//! ```
+//! use base32_simd::crockford;
+//! use base32_simd::AsOut;
+//!
+//! assert_eq!(crockford::check_symbol(b"16J").unwrap(), b'D');
+//!
+//! let mut buf = [0u8; 16];
+//! let encoded = crockford::encode_checked(b"hello", buf.as_mut_slice().as_out()).unwrap();
+//! assert_eq!(encoded, b"D1JPRV3FJ");
+//!
+//! let mut buf = [0u8; 16];
+//! let decoded = crockford::decode_checked(b"d1jp-rv3f-j", buf.as_mut_slice().as_out()).unwrap();
+//! assert_eq!(decoded, b"hello");
+//! ```
+
+use crate::decode::CROCKFORD_TABLE;
+use crate::error::ErrorKind;
+use crate::{Error, Out, CROCKFORD};
+
+use vsimd::tools::slice_mut;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+const CHECK_SYMBOLS: &[u8; 37] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ*~$=U";
+
+#[inline(always)]
+const fn check_value(c: u8) -> u8 {
+    match c {
+        b'*' => 32,
+        b'~' => 33,
+        b'$' => 34,
+        b'=' => 35,
+        b'U' | b'u' => 36,
+        _ => CROCKFORD_TABLE[c as usize],
+    }
+}
+
+/// Calculates the checksum of the data symbols of `src`, which are valid.
+#[inline]
+fn checksum(src: &[u8]) -> u8 {
+    let mut acc: u32 = 0;
+    for &x in src {
+        if x != b'-' {
+            acc = (acc * 32 + u32::from(CROCKFORD_TABLE[x as usize])) % 37;
+        }
+    }
+    acc as u8
+}
+
+/// Returns the check symbol of a Crockford's base32 string.
+///
+/// Hyphens are ignored. The string can have any length,
+/// so that the check symbols of encoded numbers are also supported.
+///
+/// # Errors
+/// This function returns `Err` if any character of `src` is invalid.
+#[inline]
+pub fn check_symbol(src: &[u8]) -> Result<u8, Error> {
+    let is_invalid = |&x: &u8| x != b'-' && CROCKFORD_TABLE[x as usize] == 0xff;
+    if let Some(offset) = src.iter().position(is_invalid) {
+        let byte = src[offset];
+        return Err(Error::from_kind(ErrorKind::InvalidCharacter { offset, byte }));
+    }
+    Ok(CHECK_SYMBOLS[checksum(src) as usize])
+}
+
+/// Encodes bytes to a Crockford's base32 string with a check symbol.
+///
+/// The output is `CROCKFORD.encoded_length(src.len()) + 1` bytes long.
+///
+/// # Errors
+/// This function returns `Err` if the length of `dst` is not enough.
+#[inline]
+pub fn encode_checked<'d>(src: &[u8], mut dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Error> {
+    let m = CROCKFORD.encoded_length(src.len());
+    ensure!(dst.len() > m, BufferTooSmall);
+
+    let ans = CROCKFORD.encode(src, dst.reborrow())?;
+    let symbol = CHECK_SYMBOLS[checksum(ans) as usize];
+    unsafe {
+        let dst = dst.as_mut_ptr();
+        dst.add(m).write(symbol);
+        Ok(slice_mut(dst, m + 1))
+    }
+}
+
+/// Splits the check symbol from the end of `src` and verifies it against the data symbols.
+///
+/// Returns the data symbols.
+#[inline]
+fn verify(src: &[u8]) -> Result<&[u8], Error> {
+    let (&symbol, data) = match src.split_last() {
+        Some(x) => x,
+        None => return Err(Error::from_kind(ErrorKind::InvalidLength)),
+    };
+    let offset = data.len();
+    let value = check_value(symbol);
+    if value == 0xff {
+        return Err(Error::from_kind(ErrorKind::InvalidCharacter { offset, byte: symbol }));
+    }
+    if check_symbol(data)? != CHECK_SYMBOLS[value as usize] {
+        return Err(Error::from_kind(ErrorKind::InvalidChecksum { offset }));
+    }
+    Ok(data)
+}
+
+/// Decodes a Crockford's base32 string with a check symbol to bytes.
+///
+/// The last character is the check symbol, which is verified before decoding.
+/// The decoding is the same as [`CROCKFORD`].
+///
+/// # Errors
+/// This function returns `Err` if
+/// + the length of `dst` is not enough.
+/// + the content of `src` is invalid.
+/// + the check symbol does not match the data.
+#[inline]
+pub fn decode_checked<'d>(src: &[u8], dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Error> {
+    let data = verify(src)?;
+    CROCKFORD.decode(data, dst)
+}
+
+/// Encodes bytes to a Crockford's base32 string with a check symbol.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+#[inline]
+#[must_use]
+pub fn encode_checked_to_string(data: impl AsRef<[u8]>) -> String {
+    let mut ans = CROCKFORD.encode_to_string(data);
+    let symbol = CHECK_SYMBOLS[checksum(ans.as_bytes()) as usize];
+    ans.push(symbol as char);
+    ans
+}
+
+/// Decodes a Crockford's base32 string with a check symbol to bytes.
+///
+/// # Errors
+/// This function returns `Err` if
+/// + the content of `data` is invalid.
+/// + the check symbol does not match the data.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn decode_checked_to_vec(data: impl AsRef<[u8]>) -> Result<Vec<u8>, Error> {
+    let data = verify(data.as_ref())?;
+    CROCKFORD.decode_to_vec(data)
+}
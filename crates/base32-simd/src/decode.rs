@@ -1,7 +1,9 @@
 use crate::alsw::{BASE32HEX_ALSW_CHECK_X2, BASE32HEX_ALSW_DECODE_X2};
 use crate::alsw::{BASE32_ALSW_CHECK_X2, BASE32_ALSW_DECODE_X2};
-use crate::{u16x4_to_u64, Error, Kind};
-use crate::{BASE32HEX_CHARSET, BASE32_CHARSET};
+use crate::error::ErrorKind;
+use crate::lookup::{decode_values, LookupLutX8};
+use crate::{u16x4_to_u64, Base32, Error, Kind};
+use crate::{BASE32HEX_CHARSET, BASE32_CHARSET, CROCKFORD_CHARSET, ZBASE32_CHARSET};
 
 use vsimd::alsw::AlswLut;
 use vsimd::isa::{AVX2, NEON, SSE41, WASM128};
@@ -23,32 +25,220 @@ const fn decoding_table(charset: &[u8; 32]) -> [u8; 256] {
     table
 }
 
+/// Crockford's decoding table is case-insensitive and maps `O` to 0 and `I`, `L` to 1.
+const fn crockford_decoding_table() -> [u8; 256] {
+    let mut table = decoding_table(CROCKFORD_CHARSET);
+    let mut i = 0;
+    while i < 32 {
+        table[CROCKFORD_CHARSET[i].to_ascii_lowercase() as usize] = i as u8;
+        i += 1;
+    }
+    let mut i = 0;
+    while i < 6 {
+        let (c, x) = [(b'O', 0), (b'o', 0), (b'I', 1), (b'i', 1), (b'L', 1), (b'l', 1)][i];
+        table[c as usize] = x;
+        i += 1;
+    }
+    table
+}
+
+/// Decodes each letter in either case, unless the other case is a different character of the charset.
+const fn case_insensitive_table(table: &[u8; 256]) -> [u8; 256] {
+    let mut table = *table;
+    let mut c = b'A';
+    while c <= b'Z' {
+        let upper = c as usize;
+        let lower = c.to_ascii_lowercase() as usize;
+        if table[lower] == 0xff {
+            table[lower] = table[upper];
+        } else if table[upper] == 0xff {
+            table[upper] = table[lower];
+        }
+        c += 1;
+    }
+    table
+}
+
 pub const BASE32_TABLE: &[u8; 256] = &decoding_table(BASE32_CHARSET);
 pub const BASE32HEX_TABLE: &[u8; 256] = &decoding_table(BASE32HEX_CHARSET);
+pub const CROCKFORD_TABLE: &[u8; 256] = &crockford_decoding_table();
+pub const ZBASE32_TABLE: &[u8; 256] = &decoding_table(ZBASE32_CHARSET);
 
+const BASE32_CASE_INSENSITIVE_TABLE: &[u8; 256] = &case_insensitive_table(BASE32_TABLE);
+const BASE32HEX_CASE_INSENSITIVE_TABLE: &[u8; 256] = &case_insensitive_table(BASE32HEX_TABLE);
+const ZBASE32_CASE_INSENSITIVE_TABLE: &[u8; 256] = &case_insensitive_table(ZBASE32_TABLE);
+
+const CROCKFORD_LOOKUP_X8: &LookupLutX8 = &LookupLutX8::new(CROCKFORD_TABLE);
+const ZBASE32_LOOKUP_X8: &LookupLutX8 = &LookupLutX8::new(ZBASE32_TABLE);
+const ZBASE32_CASE_INSENSITIVE_LOOKUP_X8: &LookupLutX8 = &LookupLutX8::new(ZBASE32_CASE_INSENSITIVE_TABLE);
+
+/// Returns the decoding table of `base32`.
+///
+/// The table of a custom charset is derived into `custom`.
+#[inline(always)]
+pub(crate) fn decoding_table_of<'a>(base32: &Base32, custom: &'a mut Option<[u8; 256]>) -> &'a [u8; 256] {
+    match (base32.kind, base32.case_insensitive) {
+        (Kind::Base32, false) => BASE32_TABLE,
+        (Kind::Base32, true) => BASE32_CASE_INSENSITIVE_TABLE,
+        (Kind::Base32Hex, false) => BASE32HEX_TABLE,
+        (Kind::Base32Hex, true) => BASE32HEX_CASE_INSENSITIVE_TABLE,
+        (Kind::Crockford, _) => CROCKFORD_TABLE,
+        (Kind::ZBase32, false) => ZBASE32_TABLE,
+        (Kind::ZBase32, true) => ZBASE32_CASE_INSENSITIVE_TABLE,
+        (Kind::Custom, false) => custom.insert(decoding_table(&base32.charset)),
+        (Kind::Custom, true) => custom.insert(case_insensitive_table(&decoding_table(&base32.charset))),
+    }
+}
+
+/// The lookup tables of the SIMD paths.
+#[derive(Clone, Copy)]
+pub(crate) enum DecodeLut<'a> {
+    Alsw(AlswLut<V256>, AlswLut<V256>),
+    /// ALSW after converting lowercase letters to uppercase
+    AlswUppercase(AlswLut<V256>, AlswLut<V256>),
+    Lookup(&'a LookupLutX8),
+}
+
+/// Returns the SIMD lookup tables of `base32`.
+///
+/// The tables of a custom charset are derived into `custom`.
+#[inline(always)]
+pub(crate) fn decode_lut_of<'a>(base32: &Base32, custom: &'a mut Option<LookupLutX8>) -> DecodeLut<'a> {
+    match (base32.kind, base32.case_insensitive) {
+        (Kind::Base32, false) => DecodeLut::Alsw(BASE32_ALSW_CHECK_X2, BASE32_ALSW_DECODE_X2),
+        (Kind::Base32, true) => DecodeLut::AlswUppercase(BASE32_ALSW_CHECK_X2, BASE32_ALSW_DECODE_X2),
+        (Kind::Base32Hex, false) => DecodeLut::Alsw(BASE32HEX_ALSW_CHECK_X2, BASE32HEX_ALSW_DECODE_X2),
+        (Kind::Base32Hex, true) => DecodeLut::AlswUppercase(BASE32HEX_ALSW_CHECK_X2, BASE32HEX_ALSW_DECODE_X2),
+        (Kind::Crockford, _) => DecodeLut::Lookup(CROCKFORD_LOOKUP_X8),
+        (Kind::ZBase32, false) => DecodeLut::Lookup(ZBASE32_LOOKUP_X8),
+        (Kind::ZBase32, true) => DecodeLut::Lookup(ZBASE32_CASE_INSENSITIVE_LOOKUP_X8),
+        (Kind::Custom, _) => DecodeLut::Lookup(custom.insert(LookupLutX8::new(decoding_table_of(base32, &mut None)))),
+    }
+}
+
+/// Returns the number of data characters and the decoded length.
 #[inline]
-pub fn decoded_length(data: &[u8], padding: bool) -> Result<(usize, usize), Error> {
+pub fn decoded_length(data: &[u8], padding: bool, kind: Kind) -> Result<(usize, usize), Error> {
     if data.is_empty() {
         return Ok((0, 0));
     }
 
     let len = data.len();
     let n = if padding {
-        ensure!(len % 8 == 0);
+        ensure!(len % 8 == 0, InvalidLength);
         let last = unsafe { data.get_unchecked(len - 6..) };
         let count = last.iter().copied().filter(|&x| x == b'=').count();
         len - count
+    } else if kind.ignores_hyphens() {
+        len - data.iter().copied().filter(|&x| x == b'-').count()
     } else {
         data.len()
     };
 
     const EXTRA: [u8; 8] = [0, 0xff, 1, 0xff, 2, 3, 0xff, 4];
     let extra = EXTRA[n % 8];
-    ensure!(extra != 0xff);
+    if extra == 0xff {
+        return Err(if padding {
+            Error::from_kind(ErrorKind::InvalidPadding { offset: n })
+        } else {
+            Error::from_kind(ErrorKind::InvalidLength)
+        });
+    }
     let m = n / 8 * 5 + extra as usize;
     Ok((n, m))
 }
 
+/// Finds the first error in `data`, whose first `n` characters are not padding.
+///
+/// The fast paths only report that the input is invalid.
+/// This function is called after they fail so that the happy path does not pay for the position.
+#[cold]
+#[inline(never)]
+pub(crate) fn locate(data: &[u8], n: usize, base32: &Base32) -> Error {
+    let mut custom = None;
+    let table = decoding_table_of(base32, &mut custom);
+    let mut i = 0;
+    let mut last = 0;
+    for (offset, &byte) in data.iter().enumerate() {
+        if byte == b'-' && base32.kind.ignores_hyphens() {
+            continue;
+        }
+        let is_valid = if i < n {
+            table[byte as usize] != 0xff
+        } else {
+            byte == b'='
+        };
+        if !is_valid {
+            return Error::from_kind(if byte == b'=' || i >= n {
+                ErrorKind::InvalidPadding { offset }
+            } else {
+                ErrorKind::InvalidCharacter { offset, byte }
+            });
+        }
+        if i < n {
+            last = offset;
+        }
+        i += 1;
+    }
+    if n > 0 {
+        // the unused bits of the last character are not zero
+        let byte = data[last];
+        return Error::from_kind(ErrorKind::InvalidCharacter { offset: last, byte });
+    }
+    Error::new()
+}
+
+/// The number of characters compacted on the stack before each call.
+const CHUNK: usize = 256;
+
+/// Calls `f` on the characters of `src` except hyphens, in blocks of `CHUNK` characters.
+/// The last block may be shorter.
+#[inline]
+pub(crate) fn for_each_without_hyphens(src: &[u8], mut f: impl FnMut(&[u8]) -> Result<(), Error>) -> Result<(), Error> {
+    let mut buf = [0u8; CHUNK];
+    let mut len = 0;
+    for &x in src {
+        if x != b'-' {
+            buf[len] = x;
+            len += 1;
+            if len == CHUNK {
+                f(&buf)?;
+                len = 0;
+            }
+        }
+    }
+    f(&buf[..len])
+}
+
+/// Removes hyphens from `data` inplace.
+#[inline]
+pub(crate) fn remove_hyphens_inplace(data: &mut [u8]) -> &mut [u8] {
+    let mut len = 0;
+    for i in 0..data.len() {
+        let x = data[i];
+        if x != b'-' {
+            data[len] = x;
+            len += 1;
+        }
+    }
+    &mut data[..len]
+}
+
+/// Decodes `src`, which has `n` data characters, to `dst`.
+#[inline]
+pub(crate) unsafe fn decode_data(src: &[u8], n: usize, mut dst: *mut u8, base32: &Base32) -> Result<(), Error> {
+    let ans = if n < src.len() && base32.kind.ignores_hyphens() {
+        for_each_without_hyphens(src, |chunk| {
+            crate::multiversion::decode::auto(chunk.as_ptr(), chunk.len(), dst, base32)?;
+            dst = dst.add(chunk.len() / 8 * 5);
+            Ok(())
+        })
+    } else {
+        crate::multiversion::decode::auto(src.as_ptr(), n, dst, base32)
+    };
+    ans.map_err(|_| locate(src, n, base32))
+}
+
 #[inline(always)]
 pub unsafe fn decode_bits<const N: usize>(src: *const u8, table: *const u8) -> (u64, u8) {
     debug_assert!(matches!(N, 2 | 4 | 5 | 7 | 8));
@@ -141,12 +331,10 @@ pub(crate) unsafe fn decode_fallback(
     mut src: *const u8,
     mut n: usize,
     mut dst: *mut u8,
-    kind: Kind,
+    base32: &Base32,
 ) -> Result<(), Error> {
-    let table = match kind {
-        Kind::Base32 => BASE32_TABLE.as_ptr(),
-        Kind::Base32Hex => BASE32HEX_TABLE.as_ptr(),
-    };
+    let mut custom = None;
+    let table = decoding_table_of(base32, &mut custom).as_ptr();
 
     let end = src.add(n / 8 * 8);
     while src < end {
@@ -167,17 +355,15 @@ pub(crate) unsafe fn decode_simd<S: SIMD256>(
     mut src: *const u8,
     mut n: usize,
     mut dst: *mut u8,
-    kind: Kind,
+    base32: &Base32,
 ) -> Result<(), Error> {
-    let (check_lut, decode_lut) = match kind {
-        Kind::Base32 => (BASE32_ALSW_CHECK_X2, BASE32_ALSW_DECODE_X2),
-        Kind::Base32Hex => (BASE32HEX_ALSW_CHECK_X2, BASE32HEX_ALSW_DECODE_X2),
-    };
+    let mut custom = None;
+    let lut = decode_lut_of(base32, &mut custom);
 
     // n*5/8 >= 10+10+6
     while n >= 42 {
         let x = s.v256_load_unaligned(src);
-        let y = try_!(decode_ascii32(s, x, check_lut, decode_lut));
+        let y = try_!(decode_ascii32(s, x, lut));
 
         let (y1, y2) = y.to_v128x2();
         s.v128_store_unaligned(dst, y1);
@@ -188,7 +374,7 @@ pub(crate) unsafe fn decode_simd<S: SIMD256>(
         n -= 32;
     }
 
-    decode_fallback(src, n, dst, kind)
+    decode_fallback(src, n, dst, base32)
 }
 
 #[inline(always)]
@@ -265,8 +451,18 @@ fn merge_bits<S: SIMD256>(s: S, x: V256) -> V256 {
 }
 
 #[inline(always)]
-fn decode_ascii32<S: SIMD256>(s: S, x: V256, check: AlswLut<V256>, decode: AlswLut<V256>) -> Result<V256, Error> {
-    let (c1, c2) = vsimd::alsw::decode_ascii_xn(s, x, check, decode);
+fn decode_ascii32<S: SIMD256>(s: S, x: V256, lut: DecodeLut<'_>) -> Result<V256, Error> {
+    let (c1, c2) = match lut {
+        DecodeLut::Alsw(check, decode) => vsimd::alsw::decode_ascii_xn(s, x, check, decode),
+        DecodeLut::AlswUppercase(check, decode) => {
+            let x = vsimd::ascii::to_ascii_uppercase(s, x);
+            vsimd::alsw::decode_ascii_xn(s, x, check, decode)
+        }
+        DecodeLut::Lookup(lut) => {
+            let c = decode_values(s, x, lut);
+            (c, c)
+        }
+    };
     let y = merge_bits(s, c2);
     ensure!(u8x32_highbit_any(s, c1).not());
     Ok(y)
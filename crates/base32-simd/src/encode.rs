@@ -1,5 +1,6 @@
-use crate::{u16x4_to_u64, Kind};
-use crate::{BASE32HEX_CHARSET, BASE32_CHARSET};
+use crate::{u16x4_to_u64, Base32, Kind};
+use crate::{BASE32HEX_CHARSET, BASE32_CHARSET, CROCKFORD_CHARSET, ZBASE32_CHARSET};
+use crate::{BASE32HEX_LOWERCASE_CHARSET, BASE32_LOWERCASE_CHARSET, CROCKFORD_LOWERCASE_CHARSET};
 
 use vsimd::isa::{NEON, SSE41, SSSE3, WASM128};
 use vsimd::tools::{read, write};
@@ -111,11 +112,14 @@ unsafe fn encode_extra(src: *const u8, extra: usize, dst: *mut u8, charset: *con
 }
 
 #[inline(always)]
-pub(crate) unsafe fn encode_fallback(mut src: *const u8, mut len: usize, mut dst: *mut u8, kind: Kind, padding: bool) {
-    let charset: *const u8 = match kind {
-        Kind::Base32 => BASE32_CHARSET.as_ptr(),
-        Kind::Base32Hex => BASE32HEX_CHARSET.as_ptr(),
-    };
+pub(crate) unsafe fn encode_fallback(
+    mut src: *const u8,
+    mut len: usize,
+    mut dst: *mut u8,
+    base32: &Base32,
+    padding: bool,
+) {
+    let charset: *const u8 = base32.charset.as_ptr();
 
     let end = src.add(len / 5 * 5);
     while src < end {
@@ -135,12 +139,19 @@ pub(crate) unsafe fn encode_simd<S: SIMD256>(
     mut src: *const u8,
     mut len: usize,
     mut dst: *mut u8,
-    kind: Kind,
+    base32: &Base32,
     padding: bool,
 ) {
-    let (charset, encoding_lut) = match kind {
-        Kind::Base32 => (BASE32_CHARSET.as_ptr(), BASE32_ENCODING_LUT),
-        Kind::Base32Hex => (BASE32HEX_CHARSET.as_ptr(), BASE32HEX_ENCODING_LUT),
+    let charset: *const u8 = base32.charset.as_ptr();
+    let encoding_lut = match (base32.kind, base32.lowercase) {
+        (Kind::Base32, false) => BASE32_ENCODING_LUT,
+        (Kind::Base32, true) => BASE32_LOWERCASE_ENCODING_LUT,
+        (Kind::Base32Hex, false) => BASE32HEX_ENCODING_LUT,
+        (Kind::Base32Hex, true) => BASE32HEX_LOWERCASE_ENCODING_LUT,
+        (Kind::Crockford, false) => CROCKFORD_ENCODING_LUT,
+        (Kind::Crockford, true) => CROCKFORD_LOWERCASE_ENCODING_LUT,
+        (Kind::ZBase32, _) => ZBASE32_ENCODING_LUT,
+        (Kind::Custom, _) => EncodingLutX2::new(&base32.charset),
     };
 
     if len >= (10 + 20 + 6) {
@@ -168,7 +179,7 @@ pub(crate) unsafe fn encode_simd<S: SIMD256>(
         }
     }
 
-    encode_fallback(src, len, dst, kind, padding);
+    encode_fallback(src, len, dst, base32, padding);
 }
 
 #[inline(always)]
@@ -229,7 +240,12 @@ impl EncodingLutX2 {
 }
 
 const BASE32_ENCODING_LUT: EncodingLutX2 = EncodingLutX2::new(BASE32_CHARSET);
+const BASE32_LOWERCASE_ENCODING_LUT: EncodingLutX2 = EncodingLutX2::new(BASE32_LOWERCASE_CHARSET);
 const BASE32HEX_ENCODING_LUT: EncodingLutX2 = EncodingLutX2::new(BASE32HEX_CHARSET);
+const BASE32HEX_LOWERCASE_ENCODING_LUT: EncodingLutX2 = EncodingLutX2::new(BASE32HEX_LOWERCASE_CHARSET);
+const CROCKFORD_ENCODING_LUT: EncodingLutX2 = EncodingLutX2::new(CROCKFORD_CHARSET);
+const CROCKFORD_LOWERCASE_ENCODING_LUT: EncodingLutX2 = EncodingLutX2::new(CROCKFORD_LOWERCASE_CHARSET);
+const ZBASE32_ENCODING_LUT: EncodingLutX2 = EncodingLutX2::new(ZBASE32_CHARSET);
 
 #[inline(always)]
 fn encode_values<S: SIMD256>(s: S, x: V256, lut: EncodingLutX2) -> V256 {
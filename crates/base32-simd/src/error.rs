@@ -1,26 +1,93 @@
 use core::fmt;
 
 /// Base32 Error
-pub struct Error(());
+pub struct Error {
+    kind: ErrorKind,
+}
+
+/// The kind of a base32 error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// An invalid character is found in the input.
+    InvalidCharacter {
+        /// The offset of the character in the input.
+        offset: usize,
+        /// The value of the character.
+        byte: u8,
+    },
+    /// The length of the input is invalid.
+    InvalidLength,
+    /// The padding is invalid.
+    InvalidPadding {
+        /// The offset of the first misplaced padding character,
+        /// or the end of the data characters if the amount of padding is wrong.
+        offset: usize,
+    },
+    /// The output buffer is too small.
+    BufferTooSmall,
+    /// The check symbol does not match the data.
+    InvalidChecksum {
+        /// The offset of the check symbol in the input.
+        offset: usize,
+    },
+    /// The input is invalid but the position of the error is unknown.
+    ///
+    /// For example, in-place decoding may have overwritten the invalid part of the input.
+    Invalid,
+}
 
 impl Error {
     #[inline(always)]
     pub(crate) const fn new() -> Self {
-        Error(())
+        Self::from_kind(ErrorKind::Invalid)
+    }
+
+    #[inline(always)]
+    pub(crate) const fn from_kind(kind: ErrorKind) -> Self {
+        Error { kind }
+    }
+
+    /// Returns the kind of this error.
+    #[inline]
+    #[must_use]
+    pub const fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Returns the offset of the error in the input, if known.
+    #[inline]
+    #[must_use]
+    pub const fn offset(&self) -> Option<usize> {
+        match self.kind {
+            ErrorKind::InvalidCharacter { offset, .. }
+            | ErrorKind::InvalidPadding { offset }
+            | ErrorKind::InvalidChecksum { offset } => Some(offset),
+            _ => None,
+        }
     }
 }
 
 impl fmt::Debug for Error {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        <str as fmt::Debug>::fmt("Base32Error", f)
+        f.debug_tuple("Base32Error").field(&self.kind).finish()
     }
 }
 
 impl fmt::Display for Error {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        <str as fmt::Display>::fmt("Base32Error", f)
+        match self.kind {
+            ErrorKind::InvalidCharacter { offset, byte } => {
+                write!(f, "Base32Error: invalid character {byte:#04x} at offset {offset}")
+            }
+            ErrorKind::InvalidLength => f.write_str("Base32Error: invalid length"),
+            ErrorKind::InvalidPadding { offset } => write!(f, "Base32Error: invalid padding at offset {offset}"),
+            ErrorKind::BufferTooSmall => f.write_str("Base32Error: buffer too small"),
+            ErrorKind::InvalidChecksum { offset } => write!(f, "Base32Error: invalid check symbol at offset {offset}"),
+            ErrorKind::Invalid => f.write_str("Base32Error"),
+        }
     }
 }
 
@@ -34,6 +101,11 @@ macro_rules! ensure {
             return Err($crate::error::Error::new());
         }
     };
+    ($cond:expr, $kind:ident) => {
+        if !$cond {
+            return Err($crate::error::Error::from_kind($crate::error::ErrorKind::$kind));
+        }
+    };
 }
 
 #[allow(unused_macros)]
@@ -0,0 +1,142 @@
+use crate::decode::{decoded_length, decoding_table_of};
+use crate::error::ErrorKind;
+use crate::{Base32, Error, Out};
+
+use vsimd::ascii::{is_separator, remove_separators_fallback, remove_separators_inplace};
+use vsimd::tools::{slice_mut, slice_parts};
+
+/// The number of characters compacted on the stack before each decoding.
+const CHUNK: usize = 256;
+
+/// Returns the position of the first ascii whitespace or hyphen, or `data.len()` if there is none.
+#[inline(always)]
+fn find_separator(data: &[u8]) -> usize {
+    let (src, len) = slice_parts(data);
+    unsafe { crate::multiversion::find_separator::auto(src, len) }
+}
+
+/// Strips the padding of a compacted string.
+///
+/// The padding is present only if the length is a multiple of 8.
+#[inline]
+fn strip_padding(data: &[u8]) -> &[u8] {
+    if data.len() % 8 != 0 {
+        return data;
+    }
+    let min = data.len().saturating_sub(6);
+    let mut n = data.len();
+    while n > min && data[n - 1] == b'=' {
+        n -= 1;
+    }
+    &data[..n]
+}
+
+/// Finds the first error in `src`, skipping separators.
+#[cold]
+#[inline(never)]
+fn locate(src: &[u8], base32: &Base32) -> Error {
+    let mut custom = None;
+    let table = decoding_table_of(base32, &mut custom);
+    let mut padding: Option<usize> = None;
+    let mut pad_count = 0;
+    let mut data_count = 0;
+    let mut last = None;
+    for (offset, &byte) in src.iter().enumerate() {
+        if is_separator::<b'-'>(byte) {
+            continue;
+        }
+        if byte == b'=' {
+            padding.get_or_insert(offset);
+            pad_count += 1;
+            continue;
+        }
+        if let Some(offset) = padding {
+            return Error::from_kind(ErrorKind::InvalidPadding { offset });
+        }
+        if table[byte as usize] == 0xff {
+            return Error::from_kind(ErrorKind::InvalidCharacter { offset, byte });
+        }
+        data_count += 1;
+        last = Some(offset);
+    }
+    if let Some(offset) = padding {
+        if pad_count > 6 || (data_count + pad_count) % 8 != 0 {
+            return Error::from_kind(ErrorKind::InvalidPadding { offset });
+        }
+    }
+    match last {
+        // the unused bits of the last character are not zero
+        Some(offset) => Error::from_kind(ErrorKind::InvalidCharacter {
+            offset,
+            byte: src[offset],
+        }),
+        None => Error::new(),
+    }
+}
+
+/// Decodes `src` after removing separators and the optional padding.
+#[inline]
+pub(crate) fn forgiving_decode<'d>(base32: &Base32, src: &[u8], mut dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Error> {
+    let pos = find_separator(src);
+    debug_assert!(pos <= src.len());
+
+    let cap = dst.len();
+
+    if pos == src.len() {
+        let data = strip_padding(src);
+        let (n, m) = decoded_length(data, false, base32.kind)?;
+        ensure!(cap >= m, BufferTooSmall);
+        unsafe {
+            let dst = dst.as_mut_ptr();
+            crate::multiversion::decode::auto(data.as_ptr(), n, dst, base32).map_err(|_| locate(src, base32))?;
+            return Ok(slice_mut(dst, m));
+        }
+    }
+
+    // The last group is held back because it may be padded.
+    let head = pos.saturating_sub(1) / 8 * 8;
+    ensure!(cap >= head / 8 * 5, BufferTooSmall);
+
+    unsafe {
+        let dst = dst.as_mut_ptr();
+        crate::multiversion::decode::auto(src.as_ptr(), head, dst, base32).map_err(|_| locate(src, base32))?;
+        let mut len = head / 8 * 5;
+
+        // The compacted characters are decoded in blocks.
+        // The last group of each block is carried to the next block.
+        let mut buf = [0u8; CHUNK + 8];
+        let mut carry = 0;
+        for chunk in src[head..].chunks(CHUNK) {
+            let n =
+                carry + remove_separators_fallback::<b'-'>(chunk.as_ptr(), chunk.len(), buf.as_mut_ptr().add(carry));
+            let k = n.saturating_sub(1) / 8 * 8;
+            ensure!(cap - len >= k / 8 * 5, BufferTooSmall);
+            crate::multiversion::decode::auto(buf.as_ptr(), k, dst.add(len), base32)
+                .map_err(|_| locate(src, base32))?;
+            len += k / 8 * 5;
+            carry = n - k;
+            buf.copy_within(k..n, 0);
+        }
+
+        let data = strip_padding(&buf[..carry]);
+        let (n, m) = decoded_length(data, false, base32.kind)?;
+        ensure!(cap - len >= m, BufferTooSmall);
+        crate::multiversion::decode::auto(data.as_ptr(), n, dst.add(len), base32).map_err(|_| locate(src, base32))?;
+
+        Ok(slice_mut(dst, len + m))
+    }
+}
+
+/// Decodes `data` inplace after removing separators and the optional padding.
+#[inline]
+pub(crate) fn forgiving_decode_inplace<'d>(base32: &Base32, data: &'d mut [u8]) -> Result<&'d mut [u8], Error> {
+    let pos = find_separator(data);
+    let data = remove_separators_inplace::<b'-'>(data, pos);
+    let (n, m) = decoded_length(strip_padding(data), false, base32.kind)?;
+    unsafe {
+        let dst: *mut u8 = data.as_mut_ptr();
+        let src: *const u8 = dst;
+        crate::multiversion::decode::auto(src, n, dst, base32)?;
+        Ok(slice_mut(dst, m))
+    }
+}
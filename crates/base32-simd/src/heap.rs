@@ -1,4 +1,4 @@
-use crate::decode::decoded_length;
+use crate::decode::{decode_data, decoded_length};
 use crate::encode::encoded_length_unchecked;
 use crate::{AppendBase32Decode, AppendBase32Encode, Base32, Error, FromBase32Decode, FromBase32Encode};
 
@@ -15,15 +15,14 @@ fn decode_to_boxed_bytes(base32: &Base32, src: &[u8]) -> Result<Box<[u8]>, Error
     }
 
     unsafe {
-        let (n, m) = decoded_length(src, base32.padding)?;
+        let (n, m) = decoded_length(src, base32.padding, base32.kind)?;
 
         // safety: 0 < m < isize::MAX
         let mut buf = alloc_uninit_bytes(m);
 
         {
-            let src: *const u8 = src.as_ptr();
             let dst: *mut u8 = buf.as_mut_ptr().cast();
-            crate::multiversion::decode::auto(src, n, dst, base32.kind)?;
+            decode_data(src, n, dst, base32)?;
         }
 
         Ok(assume_init(buf))
@@ -36,15 +35,14 @@ fn decode_append_vec(base32: &Base32, src: &[u8], buf: &mut Vec<u8>) -> Result<(
         return Ok(());
     }
 
-    let (n, m) = decoded_length(src, base32.padding)?;
+    let (n, m) = decoded_length(src, base32.padding, base32.kind)?;
 
     buf.reserve_exact(m);
     let prev_len = buf.len();
 
     unsafe {
         let dst: *mut u8 = buf.as_mut_ptr().add(prev_len);
-        let src: *const u8 = src.as_ptr();
-        crate::multiversion::decode::auto(src, n, dst, base32.kind)?;
+        decode_data(src, n, dst, base32)?;
 
         buf.set_len(prev_len + m);
         Ok(())
@@ -66,7 +64,7 @@ fn encode_to_boxed_str(base32: &Base32, src: &[u8]) -> Box<str> {
         {
             let (src, len) = slice_parts(src);
             let dst: *mut u8 = buf.as_mut_ptr().cast();
-            crate::multiversion::encode::auto(src, len, dst, base32.kind, base32.padding);
+            crate::multiversion::encode::auto(src, len, dst, base32, base32.padding);
         }
 
         boxed_str(assume_init(buf))
@@ -88,7 +86,7 @@ fn encode_append_vec(base32: &Base32, src: &[u8], buf: &mut Vec<u8>) {
     unsafe {
         let (src, len) = slice_parts(src);
         let dst = buf.as_mut_ptr().add(prev_len);
-        crate::multiversion::encode::auto(src, len, dst, base32.kind, base32.padding);
+        crate::multiversion::encode::auto(src, len, dst, base32, base32.padding);
 
         buf.set_len(prev_len + m);
     }
@@ -49,12 +49,17 @@ extern crate alloc;
 
 #[macro_use]
 mod error;
-pub use self::error::Error;
+pub use self::error::{Error, ErrorKind};
 
 mod alsw;
 mod check;
 mod decode;
 mod encode;
+mod forgiving;
+mod lookup;
+
+pub mod crockford;
+pub mod zbase32;
 
 mod multiversion;
 
@@ -65,16 +70,25 @@ pub use outref::{AsOut, Out};
 
 // -----------------------------------------------------------------------------
 
-use crate::decode::decoded_length;
+use crate::check::check_data;
+use crate::decode::{decode_data, decoded_length, remove_hyphens_inplace};
 use crate::encode::encoded_length_unchecked;
 
 use vsimd::tools::{slice_mut, slice_parts};
 
+use core::fmt;
+
 #[cfg(feature = "alloc")]
 use alloc::{string::String, vec::Vec};
 
 const BASE32_CHARSET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
 const BASE32HEX_CHARSET: &[u8; 32] = b"0123456789ABCDEFGHIJKLMNOPQRSTUV";
+const CROCKFORD_CHARSET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const ZBASE32_CHARSET: &[u8; 32] = b"ybndrfg8ejkmcpqxot1uwisza345h769";
+
+const BASE32_LOWERCASE_CHARSET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+const BASE32HEX_LOWERCASE_CHARSET: &[u8; 32] = b"0123456789abcdefghijklmnopqrstuv";
+const CROCKFORD_LOWERCASE_CHARSET: &[u8; 32] = b"0123456789abcdefghjkmnpqrstvwxyz";
 
 #[inline(always)]
 const fn u16x4_to_u64(x: [u16; 4]) -> u64 {
@@ -82,50 +96,283 @@ const fn u16x4_to_u64(x: [u16; 4]) -> u64 {
 }
 
 /// Base32 variant
-#[derive(Debug)]
 pub struct Base32 {
+    charset: [u8; 32],
     kind: Kind,
+    /// Whether the charset is converted to lowercase letters
+    lowercase: bool,
+    /// Whether letters are decoded in either case
+    case_insensitive: bool,
     padding: bool,
 }
 
+impl fmt::Debug for Base32 {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let charset = core::str::from_utf8(&self.charset).unwrap_or_default();
+        f.debug_struct("Base32")
+            .field("charset", &charset)
+            .field("case_insensitive", &self.case_insensitive)
+            .field("padding", &self.padding)
+            .finish_non_exhaustive()
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) enum Kind {
     Base32,
     Base32Hex,
+    Crockford,
+    ZBase32,
+    Custom,
+}
+
+impl Kind {
+    /// Whether hyphens are ignored when decoding
+    #[inline(always)]
+    #[must_use]
+    pub(crate) const fn ignores_hyphens(self) -> bool {
+        matches!(self, Kind::Crockford)
+    }
 }
 
 /// `Base32` charset with padding.
 pub const BASE32: Base32 = Base32 {
+    charset: *BASE32_CHARSET,
     kind: Kind::Base32,
+    lowercase: false,
+    case_insensitive: false,
     padding: true,
 };
 
 /// `Base32Hex` charset with padding.
 pub const BASE32HEX: Base32 = Base32 {
+    charset: *BASE32HEX_CHARSET,
     kind: Kind::Base32Hex,
+    lowercase: false,
+    case_insensitive: false,
     padding: true,
 };
 
 /// `Base32` charset withnot padding.
 pub const BASE32_NO_PAD: Base32 = Base32 {
+    charset: *BASE32_CHARSET,
     kind: Kind::Base32,
+    lowercase: false,
+    case_insensitive: false,
     padding: false,
 };
 
 /// `Base32Hex` charset withnot padding.
 pub const BASE32HEX_NO_PAD: Base32 = Base32 {
+    charset: *BASE32HEX_CHARSET,
     kind: Kind::Base32Hex,
+    lowercase: false,
+    case_insensitive: false,
+    padding: false,
+};
+
+/// Crockford's base32 charset without padding.
+///
+/// Encoding outputs uppercase characters.
+/// Decoding is case-insensitive, maps `O` to `0` and `I`, `L` to `1`, and ignores hyphens.
+///
+/// See <https://www.crockford.com/base32.html>.
+/// The check symbols are supported by the [`crockford`] module.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "alloc")]
+/// # {
+/// use base32_simd::CROCKFORD;
+///
+/// let encoded = CROCKFORD.encode_to_string(b"hello");
+/// assert_eq!(encoded, "D1JPRV3F");
+///
+/// let decoded = CROCKFORD.decode_to_vec("d1jp-rv3f").unwrap();
+/// assert_eq!(decoded, b"hello");
+///
+/// let decoded = CROCKFORD.decode_to_vec("DIJP-RV3F").unwrap();
+/// assert_eq!(decoded, b"hello");
+/// # }
+/// ```
+pub const CROCKFORD: Base32 = Base32 {
+    charset: *CROCKFORD_CHARSET,
+    kind: Kind::Crockford,
+    lowercase: false,
+    case_insensitive: true,
+    padding: false,
+};
+
+/// `z-base-32` charset without padding.
+///
+/// See <https://philzimmermann.com/docs/human-oriented-base-32-encoding.txt>.
+/// Bit lengths which are not a multiple of 8 are supported by the [`zbase32`] module.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "alloc")]
+/// # {
+/// use base32_simd::ZBASE32;
+///
+/// let encoded = ZBASE32.encode_to_string(b"hello");
+/// assert_eq!(encoded, "pb1sa5dx");
+///
+/// let decoded = ZBASE32.decode_to_vec(encoded).unwrap();
+/// assert_eq!(decoded, b"hello");
+/// # }
+/// ```
+pub const ZBASE32: Base32 = Base32 {
+    charset: *ZBASE32_CHARSET,
+    kind: Kind::ZBase32,
+    lowercase: false,
+    case_insensitive: false,
     padding: false,
 };
 
 impl Base32 {
+    /// Creates a variant with a custom charset.
+    ///
+    /// The variant holds a copy of `charset`.
+    /// The decoding table and the SIMD lookup vectors are derived from it when decoding,
+    /// so that the variant stays small and can be defined as a constant.
+    ///
+    /// Decoding is case-sensitive unless [`case_insensitive`](Self::case_insensitive) is applied.
+    ///
+    /// # Panics
+    /// This function panics if
+    /// + `charset` contains a non-ascii character.
+    /// + `charset` contains a duplicate character.
+    /// + `charset` contains `-`, `=` or an ascii whitespace,
+    ///   which are removed by the forgiving decoding functions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "alloc")]
+    /// # {
+    /// use base32_simd::Base32;
+    ///
+    /// const BECH32: Base32 = Base32::custom(b"qpzry9x8gf2tvdw0s3jn54khce6mua7l", false);
+    ///
+    /// let encoded = BECH32.encode_to_string(b"hello");
+    /// assert_eq!(encoded, "dpjkcmr0");
+    ///
+    /// let decoded = BECH32.decode_to_vec(encoded).unwrap();
+    /// assert_eq!(decoded, b"hello");
+    ///
+    /// // the charset does not need to be static
+    /// let mut charset = *b"0123456789ABCDFGHIJKLMNPQRSVWXYZ";
+    /// charset.make_ascii_lowercase();
+    /// let nix = Base32::custom(&charset, false);
+    /// assert_eq!(nix.encode_to_string(b"hello"), "d1jnqv3g");
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn custom(charset: &[u8; 32], padding: bool) -> Self {
+        let mut i = 0;
+        while i < 32 {
+            let c = charset[i];
+            assert!(c.is_ascii(), "base32 charset contains a non-ascii character");
+            assert!(
+                !(c == b'-' || c == b'=' || c.is_ascii_whitespace()),
+                "base32 charset contains a separator or padding character"
+            );
+            let mut j = 0;
+            while j < i {
+                assert!(charset[j] != c, "base32 charset contains a duplicate character");
+                j += 1;
+            }
+            i += 1;
+        }
+        Self {
+            charset: *charset,
+            kind: Kind::Custom,
+            lowercase: false,
+            case_insensitive: false,
+            padding,
+        }
+    }
+
     /// Returns the character set.
     #[inline]
     #[must_use]
-    pub fn charset(&self) -> &'static [u8; 32] {
-        match self.kind {
-            Kind::Base32 => BASE32_CHARSET,
-            Kind::Base32Hex => BASE32HEX_CHARSET,
+    pub const fn charset(&self) -> &[u8; 32] {
+        &self.charset
+    }
+
+    /// Returns a variant which encodes to lowercase letters and decodes case-insensitively.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "alloc")]
+    /// # {
+    /// use base32_simd::{Base32, BASE32HEX_NO_PAD};
+    ///
+    /// const NSEC3: Base32 = BASE32HEX_NO_PAD.lowercase();
+    ///
+    /// let encoded = NSEC3.encode_to_string(b"hello");
+    /// assert_eq!(encoded, "d1imor3f");
+    ///
+    /// let decoded = NSEC3.decode_to_vec("D1IMor3f").unwrap();
+    /// assert_eq!(decoded, b"hello");
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    /// This function panics if the lowercase charset contains a duplicate character,
+    /// which happens for a custom charset with both cases of a letter.
+    #[inline]
+    #[must_use]
+    pub const fn lowercase(mut self) -> Self {
+        let mut i = 0;
+        while i < 32 {
+            let c = self.charset[i].to_ascii_lowercase();
+            let mut j = 0;
+            while j < i {
+                assert!(
+                    self.charset[j] != c,
+                    "lowercase base32 charset contains a duplicate character"
+                );
+                j += 1;
+            }
+            self.charset[i] = c;
+            i += 1;
+        }
+        self.lowercase = true;
+        self.case_insensitive = true;
+        self
+    }
+
+    /// Returns a variant which decodes case-insensitively.
+    ///
+    /// The encoding output is not changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "alloc")]
+    /// # {
+    /// use base32_simd::{Base32, BASE32_NO_PAD};
+    ///
+    /// const TOTP: Base32 = BASE32_NO_PAD.case_insensitive();
+    ///
+    /// let decoded = TOTP.decode_to_vec("jbswy3dpehpk3pxp").unwrap();
+    /// assert_eq!(decoded, b"Hello!\xde\xad\xbe\xef");
+    ///
+    /// assert_eq!(TOTP.encode_to_string(&decoded), "JBSWY3DPEHPK3PXP");
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn case_insensitive(self) -> Self {
+        Self {
+            case_insensitive: true,
+            ..self
         }
     }
 
@@ -161,7 +408,7 @@ impl Base32 {
     /// This function returns `Err` if the content of `data` is partially invalid.
     #[inline]
     pub fn decoded_length(&self, data: &[u8]) -> Result<usize, Error> {
-        let (_, m) = decoded_length(data, self.padding)?;
+        let (_, m) = decoded_length(data, self.padding, self.kind)?;
         Ok(m)
     }
 
@@ -171,9 +418,8 @@ impl Base32 {
     /// This function returns `Err` if the content of `data` is invalid.
     #[inline]
     pub fn check(&self, data: &[u8]) -> Result<(), Error> {
-        let (n, _) = decoded_length(data, self.padding)?;
-        let src = data.as_ptr();
-        unsafe { crate::multiversion::check::auto(src, n, self.kind) }
+        let (n, _) = decoded_length(data, self.padding, self.kind)?;
+        check_data(data, n, self)
     }
 
     /// Encodes bytes to a base32 string.
@@ -184,11 +430,11 @@ impl Base32 {
     pub fn encode<'d>(&self, src: &[u8], mut dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Error> {
         unsafe {
             let m = encoded_length_unchecked(src.len(), self.padding);
-            ensure!(dst.len() >= m);
+            ensure!(dst.len() >= m, BufferTooSmall);
 
             let (src, len) = slice_parts(src);
             let dst = dst.as_mut_ptr();
-            self::multiversion::encode::auto(src, len, dst, self.kind, self.padding);
+            self::multiversion::encode::auto(src, len, dst, self, self.padding);
 
             Ok(slice_mut(dst, m))
         }
@@ -213,12 +459,11 @@ impl Base32 {
     #[inline]
     pub fn decode<'d>(&self, src: &[u8], mut dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Error> {
         unsafe {
-            let (n, m) = decoded_length(src, self.padding)?;
-            ensure!(dst.len() >= m);
+            let (n, m) = decoded_length(src, self.padding, self.kind)?;
+            ensure!(dst.len() >= m, BufferTooSmall);
 
-            let src = src.as_ptr();
             let dst = dst.as_mut_ptr();
-            self::multiversion::decode::auto(src, n, dst, self.kind)?;
+            decode_data(src, n, dst, self)?;
 
             Ok(slice_mut(dst, m))
         }
@@ -230,17 +475,83 @@ impl Base32 {
     /// This function returns `Err` if the content of `data` is invalid.
     #[inline]
     pub fn decode_inplace<'d>(&'_ self, data: &'d mut [u8]) -> Result<&'d mut [u8], Error> {
+        let data = if self.kind.ignores_hyphens() {
+            remove_hyphens_inplace(data)
+        } else {
+            data
+        };
         unsafe {
-            let (n, m) = decoded_length(data, self.padding)?;
+            let (n, m) = decoded_length(data, self.padding, self.kind)?;
 
             let dst: *mut u8 = data.as_mut_ptr();
             let src: *const u8 = dst;
-            crate::multiversion::decode::auto(src, n, dst, self.kind)?;
+            crate::multiversion::decode::auto(src, n, dst, self)?;
 
             Ok(slice_mut(dst, m))
         }
     }
 
+    /// Forgiving decodes a base32 string to bytes.
+    ///
+    /// ASCII whitespaces and hyphens are removed before decoding,
+    /// and the padding is accepted whether it is present or not.
+    /// The padding setting of `self` is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use base32_simd::{AsOut, BASE32};
+    ///
+    /// let base32 = BASE32.case_insensitive();
+    /// let mut buf = [0u8; 16];
+    /// let ans = base32.forgiving_decode(b"jbsw Y3DP ehpk-3PXP", buf.as_mut_slice().as_out());
+    /// assert_eq!(ans.unwrap(), b"Hello!\xde\xad\xbe\xef");
+    /// ```
+    ///
+    /// # Errors
+    /// This function returns `Err` if
+    /// + the length of `dst` is not enough.
+    /// + the content of `src` is invalid.
+    #[inline]
+    pub fn forgiving_decode<'d>(&self, src: &[u8], dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Error> {
+        forgiving::forgiving_decode(self, src, dst)
+    }
+
+    /// Forgiving decodes a base32 string to bytes and writes inplace.
+    ///
+    /// ASCII whitespaces and hyphens are removed before decoding,
+    /// and the padding is accepted whether it is present or not.
+    ///
+    /// The position of an invalid character is not reported
+    /// because the input is compacted and overwritten inplace.
+    ///
+    /// # Errors
+    /// This function returns `Err` if the content of `data` is invalid.
+    #[inline]
+    pub fn forgiving_decode_inplace<'d>(&'_ self, data: &'d mut [u8]) -> Result<&'d mut [u8], Error> {
+        forgiving::forgiving_decode_inplace(self, data)
+    }
+
+    /// Forgiving decodes a base32 string to bytes and returns a new [`Vec<u8>`](Vec).
+    ///
+    /// ASCII whitespaces and hyphens are removed before decoding,
+    /// and the padding is accepted whether it is present or not.
+    ///
+    /// # Errors
+    /// This function returns `Err` if the content of `data` is invalid.
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn forgiving_decode_to_vec(&self, data: impl AsRef<[u8]>) -> Result<Vec<u8>, Error> {
+        let data = data.as_ref();
+        let mut buf = Vec::with_capacity(self.estimated_decoded_length(data.len()));
+        let len = self
+            .forgiving_decode(data, Out::from_uninit_slice(buf.spare_capacity_mut()))?
+            .len();
+        unsafe { buf.set_len(len) };
+        Ok(buf)
+    }
+
     /// Encodes bytes to a base32 string and returns a specified type.
     #[inline]
     #[must_use]
@@ -0,0 +1,82 @@
+//! Table lookup for charsets which can not be classified by ALSW.
+//!
+//! ALSW decodes a character `c` to `c + offset[hash(c)]`, so a charset needs
+//! at most 16 distinct values of `index - c`, one for each entry of the offset table.
+//! z-base-32 needs 17, and so does Crockford's base32 with its lowercase letters
+//! and the aliases of `0` and `1`. No hash function can fit them.
+//!
+//! The ascii range is split into 8 rows of 16 characters.
+//! Each row is looked up by the low nibble and selected by the high nibble.
+
+use vsimd::vector::V256;
+use vsimd::SIMD256;
+
+#[derive(Debug, Clone, Copy)]
+pub struct LookupLutX8 {
+    rows: [V256; 8],
+}
+
+impl LookupLutX8 {
+    /// Builds the rows from a decoding table, whose invalid entries are `0xff`.
+    pub const fn new(table: &[u8; 256]) -> Self {
+        let mut rows = [V256::from_bytes([0; 32]); 8];
+        let mut i = 0;
+        while i < 8 {
+            let mut row = [0; 16];
+            let mut j = 0;
+            while j < 16 {
+                row[j] = table[i * 16 + j];
+                j += 1;
+            }
+            rows[i] = V256::double_bytes(row);
+            i += 1;
+        }
+        Self { rows }
+    }
+}
+
+/// Decodes ascii characters to values.
+///
+/// The high bit of a value is set if the character is invalid.
+#[inline(always)]
+pub fn decode_values<S: SIMD256>(s: S, x: V256, lut: &LookupLutX8) -> V256 {
+    let nibble = s.u8x32_splat(0x0f);
+    let lo = s.v256_and(x, nibble);
+    let hi = s.v256_and(s.u16x16_shr::<4>(x), nibble);
+
+    // non-ascii characters are invalid
+    let mut values = s.v256_and(x, s.u8x32_splat(0x80));
+
+    let mut i = 0;
+    while i < 8 {
+        let row = s.u8x16x2_swizzle(lut.rows[i], lo);
+        let is_row = s.u8x32_eq(hi, s.u8x32_splat(i as u8));
+        values = s.v256_or(values, s.v256_and(row, is_row));
+        i += 1;
+    }
+
+    values
+}
+
+#[cfg(test)]
+mod algorithm {
+    use crate::decode::{CROCKFORD_TABLE, ZBASE32_TABLE};
+
+    fn count_offsets(table: &[u8; 256]) -> usize {
+        let mut offsets = [false; 256];
+        for c in 0..=255u8 {
+            let idx = table[c as usize];
+            if idx != 0xff {
+                offsets[idx.wrapping_sub(c) as usize] = true;
+            }
+        }
+        offsets.iter().filter(|&&x| x).count()
+    }
+
+    #[test]
+    #[ignore]
+    fn alsw_offsets() {
+        assert!(count_offsets(ZBASE32_TABLE) > 16);
+        assert!(count_offsets(CROCKFORD_TABLE) > 16);
+    }
+}
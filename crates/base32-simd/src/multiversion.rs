@@ -1,8 +1,8 @@
-use crate::{Error, Kind};
+use crate::{Base32, Error};
 
 vsimd::dispatch!(
     name        = {check},
-    signature   = {pub(crate) unsafe fn(src: *const u8, len: usize, kind: Kind) -> Result<(), Error>},
+    signature   = {pub(crate) unsafe fn(src: *const u8, len: usize, base32: &Base32) -> Result<(), Error>},
     fallback    = {crate::check::check_fallback},
     simd        = {crate::check::check_simd},
     targets     = {"avx2", "ssse3", "neon", "simd128"},
@@ -11,7 +11,7 @@ vsimd::dispatch!(
 
 vsimd::dispatch!(
     name        = {decode},
-    signature   = {pub(crate) unsafe fn(src: *const u8, len: usize, dst: *mut u8, kind: Kind) -> Result<(), Error>},
+    signature   = {pub(crate) unsafe fn(src: *const u8, len: usize, dst: *mut u8, base32: &Base32) -> Result<(), Error>},
     fallback    = {crate::decode::decode_fallback},
     simd        = {crate::decode::decode_simd},
     targets     = {"avx2", "sse4.1", "neon", "simd128"},
@@ -20,9 +20,18 @@ vsimd::dispatch!(
 
 vsimd::dispatch!(
     name        = {encode},
-    signature   = {pub(crate) unsafe fn(src: *const u8, len: usize, dst: *mut u8, kind: Kind, padding: bool) -> ()},
+    signature   = {pub(crate) unsafe fn(src: *const u8, len: usize, dst: *mut u8, base32: &Base32, padding: bool) -> ()},
     fallback    = {crate::encode::encode_fallback},
     simd        = {crate::encode::encode_simd},
     targets     = {"avx2", "sse4.1", "neon", "simd128"},
     fastest     = {"avx2", "neon", "simd128"},
 );
+
+vsimd::dispatch!(
+    name        = {find_separator},
+    signature   = {pub unsafe fn(src: *const u8, len: usize) -> usize},
+    fallback    = {vsimd::ascii::find_separator_fallback::<b'-'>},
+    simd        = {vsimd::ascii::find_separator_simd::<_, b'-'>},
+    targets     = {"avx2", "sse2", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
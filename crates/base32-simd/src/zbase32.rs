@@ -0,0 +1,178 @@
+//! Bit-granular encoding and decoding of z-base-32.
+//!
+//! z-base-32 can encode a bit string whose length is not a multiple of 8.
+//! The bits are taken from the bytes in big-endian order,
+//! and the unused bits of the last character are zero.
+//!
+//! See <https://philzimmermann.com/docs/human-oriented-base-32-encoding.txt>.
+//!
+//! # Examples
+//!
+//! ```
+//! use base32_simd::zbase32;
+//! use base32_simd::AsOut;
+//!
+//! let mut buf = [0u8; 8];
+//! let encoded = zbase32::encode_bits(&[0x8b, 0x88, 0x80], 20, buf.as_mut_slice().as_out()).unwrap();
+//! assert_eq!(encoded, b"tqre");
+//!
+//! let mut buf = [0u8; 8];
+//! let decoded = zbase32::decode_bits(b"tqre", 20, buf.as_mut_slice().as_out()).unwrap();
+//! assert_eq!(decoded, [0x8b, 0x88, 0x80]);
+//! ```
+
+use crate::decode::ZBASE32_TABLE;
+use crate::error::ErrorKind;
+use crate::{AsOut, Error, Out, ZBASE32};
+
+use vsimd::tools::slice_mut;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+#[inline(always)]
+const fn div_ceil(n: usize, d: usize) -> usize {
+    n / d + (n % d != 0) as usize
+}
+
+/// Calculates the encoded length of `bits` bits.
+#[inline]
+#[must_use]
+pub const fn encoded_length_bits(bits: usize) -> usize {
+    div_ceil(bits, 5)
+}
+
+/// Calculates the number of bytes which hold `bits` bits.
+#[inline]
+#[must_use]
+pub const fn decoded_length_bits(bits: usize) -> usize {
+    div_ceil(bits, 8)
+}
+
+/// Encodes the first `bits` bits of `src` to a z-base-32 string.
+///
+/// The bits after the first `bits` bits are ignored.
+///
+/// # Errors
+/// This function returns `Err` if
+/// + `src` has less than `bits` bits.
+/// + the length of `dst` is not enough.
+#[inline]
+pub fn encode_bits<'d>(src: &[u8], bits: usize, mut dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Error> {
+    let k = decoded_length_bits(bits);
+    ensure!(src.len() >= k, InvalidLength);
+    let m = encoded_length_bits(bits);
+    ensure!(dst.len() >= m, BufferTooSmall);
+
+    if k == 0 {
+        return unsafe { Ok(slice_mut(dst.as_mut_ptr(), 0)) };
+    }
+
+    // The whole groups of 5 bytes are encoded directly.
+    // The last group is masked and encoded on the stack.
+    let head = (k - 1) / 5 * 5;
+    let head_len = ZBASE32.encode(&src[..head], dst.reborrow())?.len();
+
+    let mut tail = [0u8; 5];
+    let tail_len = k - head;
+    tail[..tail_len].copy_from_slice(&src[head..k]);
+    if bits % 8 != 0 {
+        tail[tail_len - 1] &= 0xff << (8 - bits % 8);
+    }
+    let mut buf = [0u8; 8];
+    let encoded = ZBASE32.encode(&tail[..tail_len], buf.as_mut_slice().as_out())?;
+
+    unsafe {
+        let dst = dst.as_mut_ptr();
+        let rest = m - head_len;
+        core::ptr::copy_nonoverlapping(encoded.as_ptr(), dst.add(head_len), rest);
+        Ok(slice_mut(dst, m))
+    }
+}
+
+/// Decodes a z-base-32 string of `bits` bits to bytes.
+///
+/// The output is [`decoded_length_bits(bits)`](decoded_length_bits) bytes long,
+/// and the unused bits of the last byte are zero.
+///
+/// # Errors
+/// This function returns `Err` if
+/// + the length of `src` is not [`encoded_length_bits(bits)`](encoded_length_bits).
+/// + the length of `dst` is not enough.
+/// + the content of `src` is invalid, including non-zero unused bits.
+#[inline]
+pub fn decode_bits<'d>(src: &[u8], bits: usize, mut dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Error> {
+    let n = encoded_length_bits(bits);
+    ensure!(src.len() == n, InvalidLength);
+    let k = decoded_length_bits(bits);
+    ensure!(dst.len() >= k, BufferTooSmall);
+
+    if n == 0 {
+        return unsafe { Ok(slice_mut(dst.as_mut_ptr(), 0)) };
+    }
+
+    // The whole groups of 8 characters are decoded directly.
+    // The last group is decoded by bits.
+    let head = (n - 1) / 8 * 8;
+    let head_len = ZBASE32.decode(&src[..head], dst.reborrow())?.len();
+
+    let mut value: u64 = 0;
+    for (i, &x) in src[head..].iter().enumerate() {
+        let y = ZBASE32_TABLE[x as usize];
+        if y == 0xff {
+            let offset = head + i;
+            return Err(Error::from_kind(ErrorKind::InvalidCharacter { offset, byte: x }));
+        }
+        value = (value << 5) | u64::from(y);
+    }
+
+    let tail_bits = bits - head * 5;
+    let unused = (n - head) * 5 - tail_bits;
+    if value & ((1 << unused) - 1) != 0 {
+        let offset = n - 1;
+        return Err(Error::from_kind(ErrorKind::InvalidCharacter {
+            offset,
+            byte: src[offset],
+        }));
+    }
+
+    let tail_len = k - head_len;
+    let value = (value >> unused) << (tail_len * 8 - tail_bits);
+    let bytes = value.to_be_bytes();
+
+    unsafe {
+        let dst = dst.as_mut_ptr();
+        core::ptr::copy_nonoverlapping(bytes[8 - tail_len..].as_ptr(), dst.add(head_len), tail_len);
+        Ok(slice_mut(dst, k))
+    }
+}
+
+/// Encodes the first `bits` bits of `data` to a z-base-32 string.
+///
+/// # Errors
+/// This function returns `Err` if `data` has less than `bits` bits.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn encode_bits_to_string(data: impl AsRef<[u8]>, bits: usize) -> Result<String, Error> {
+    let mut buf = Vec::with_capacity(encoded_length_bits(bits));
+    let len = encode_bits(data.as_ref(), bits, Out::from_uninit_slice(buf.spare_capacity_mut()))?.len();
+    unsafe {
+        buf.set_len(len);
+        Ok(String::from_utf8_unchecked(buf))
+    }
+}
+
+/// Decodes a z-base-32 string of `bits` bits to bytes.
+///
+/// # Errors
+/// This function returns `Err` if the content of `data` is invalid.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn decode_bits_to_vec(data: impl AsRef<[u8]>, bits: usize) -> Result<Vec<u8>, Error> {
+    let mut buf = Vec::with_capacity(decoded_length_bits(bits));
+    let len = decode_bits(data.as_ref(), bits, Out::from_uninit_slice(buf.spare_capacity_mut()))?.len();
+    unsafe { buf.set_len(len) };
+    Ok(buf)
+}
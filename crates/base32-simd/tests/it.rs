@@ -1,5 +1,5 @@
 use base32_simd::{AsOut, Base32};
-use base32_simd::{BASE32, BASE32HEX, BASE32HEX_NO_PAD, BASE32_NO_PAD};
+use base32_simd::{BASE32, BASE32HEX, BASE32HEX_NO_PAD, BASE32_NO_PAD, CROCKFORD, ZBASE32};
 
 fn rand_bytes(n: usize) -> Vec<u8> {
     use rand::RngCore;
@@ -123,6 +123,12 @@ fn random() {
             BASE32HEX,        //
             BASE32_NO_PAD,    //
             BASE32HEX_NO_PAD, //
+            CROCKFORD,        //
+            ZBASE32,          //
+            BASE32.lowercase(),
+            BASE32HEX_NO_PAD.lowercase(),
+            BASE32_NO_PAD.case_insensitive(),
+            ZBASE32.case_insensitive(),
         ];
 
         for base32 in test_config {
@@ -142,3 +148,431 @@ fn random() {
         }
     }
 }
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn error_kind() {
+    use base32_simd::ErrorKind;
+
+    let invalid = |offset, byte| ErrorKind::InvalidCharacter { offset, byte };
+
+    for n in [5, 10, 20, 40, 100] {
+        let src = BASE32.encode_type::<Vec<u8>>(rand_bytes(n));
+        for i in 0..src.len() {
+            let mut bad = src.clone();
+            bad[i] = b'!';
+            let err = BASE32.check(&bad).unwrap_err();
+            assert_eq!(err.kind(), invalid(i, b'!'));
+            assert_eq!(err.offset(), Some(i));
+
+            let mut buf = vec![0; n];
+            let err = BASE32.decode(&bad, buf.as_out()).unwrap_err();
+            assert_eq!(err.kind(), invalid(i, b'!'));
+        }
+    }
+
+    let mut buf = [0; 16];
+    let decode = |base32: &Base32, src: &[u8], buf: &mut [u8]| base32.decode(src, buf.as_out()).unwrap_err().kind();
+
+    assert_eq!(decode(&BASE32, b"MZXW6", &mut buf), ErrorKind::InvalidLength);
+    assert_eq!(decode(&BASE32_NO_PAD, b"MZX", &mut buf), ErrorKind::InvalidLength);
+    assert_eq!(
+        decode(&BASE32, b"MZX=====", &mut buf),
+        ErrorKind::InvalidPadding { offset: 3 }
+    );
+    assert_eq!(
+        decode(&BASE32, b"MZ=W6===", &mut buf),
+        ErrorKind::InvalidPadding { offset: 2 }
+    );
+    assert_eq!(
+        decode(&BASE32, b"MZXW6===MZXW6===", &mut buf),
+        ErrorKind::InvalidPadding { offset: 5 }
+    );
+    assert_eq!(decode(&BASE32, b"MZXW7===", &mut buf), invalid(4, b'7'));
+    assert_eq!(decode(&BASE32, b"MZXW6ZZZ", &mut buf[..2]), ErrorKind::BufferTooSmall);
+
+    let err = BASE32.decode(b"MZ!W6===", buf.as_mut_slice().as_out()).unwrap_err();
+    assert_eq!(err.to_string(), "Base32Error: invalid character 0x21 at offset 2");
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn crockford() {
+    use base32_simd::crockford;
+    use base32_simd::ErrorKind;
+
+    assert_eq!(CROCKFORD.charset(), b"0123456789ABCDEFGHJKMNPQRSTVWXYZ");
+    assert_eq!(CROCKFORD.encode_to_string(b"foobar"), "CSQPYRK1E8");
+
+    for n in [0, 1, 5, 20, 26, 27, 100, 1000] {
+        let bytes = rand_bytes(n);
+        let encoded = CROCKFORD.encode_to_string(&bytes);
+
+        let lower = encoded.to_ascii_lowercase();
+        let aliased = encoded.replace('0', "o").replace('1', "L");
+        let hyphenated: String = encoded
+            .as_bytes()
+            .chunks(4)
+            .map(|c| core::str::from_utf8(c).unwrap())
+            .collect::<Vec<_>>()
+            .join("-");
+
+        for src in [&encoded, &lower, &aliased, &hyphenated] {
+            assert!(CROCKFORD.check(src.as_bytes()).is_ok());
+            assert_eq!(CROCKFORD.decoded_length(src.as_bytes()).unwrap(), n);
+            assert_eq!(CROCKFORD.decode_to_vec(src).unwrap(), bytes);
+
+            let mut buf = vec![0; n];
+            assert_eq!(CROCKFORD.decode(src.as_bytes(), buf.as_out()).unwrap(), bytes);
+
+            let mut buf = src.clone().into_bytes();
+            assert_eq!(CROCKFORD.decode_inplace(&mut buf).unwrap(), bytes);
+        }
+
+        let checked = crockford::encode_checked_to_string(&bytes);
+        assert_eq!(checked[..encoded.len()], encoded);
+        assert_eq!(crockford::decode_checked_to_vec(&checked).unwrap(), bytes);
+
+        let mut buf = vec![0; checked.len()];
+        assert_eq!(
+            crockford::encode_checked(&bytes, buf.as_out()).unwrap(),
+            checked.as_bytes()
+        );
+        let mut buf = vec![0; n];
+        let ans = crockford::decode_checked(
+            format!("{hyphenated}-{}", &checked[encoded.len()..]).as_bytes(),
+            buf.as_out(),
+        );
+        assert_eq!(ans.unwrap(), bytes);
+    }
+
+    assert_eq!(crockford::check_symbol(b"0").unwrap(), b'0');
+    assert_eq!(crockford::check_symbol(b"10").unwrap(), b'*');
+    assert_eq!(crockford::check_symbol(b"11").unwrap(), b'~');
+    assert_eq!(crockford::check_symbol(b"14").unwrap(), b'U');
+    assert_eq!(crockford::check_symbol(b"1-4").unwrap(), b'U');
+    assert_eq!(crockford::check_symbol(b"16J").unwrap(), b'D');
+    assert_eq!(crockford::check_symbol(b"16j").unwrap(), b'D');
+    assert_eq!(
+        crockford::decode_checked_to_vec("CSQPYRK1E8").unwrap_err().kind(),
+        ErrorKind::InvalidChecksum { offset: 9 }
+    );
+
+    let err = crockford::check_symbol(b"16U").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidCharacter { offset: 2, byte: b'U' });
+    let err = crockford::decode_checked_to_vec("CSQPYRK1E8!").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidCharacter { offset: 10, byte: b'!' });
+    let err = crockford::decode_checked_to_vec("").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidLength);
+
+    let err = CROCKFORD.decode_to_vec("CSQP-YRKU-E8").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidCharacter { offset: 8, byte: b'U' });
+    let err = CROCKFORD.check(b"CSQP-YRK1-E9").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidCharacter { offset: 11, byte: b'9' });
+    let err = CROCKFORD.decode_to_vec("CSQ-").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidLength);
+    let err = CROCKFORD.decode_to_vec("CSQPYRK1E8==").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidPadding { offset: 10 });
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn zbase32() {
+    use base32_simd::zbase32;
+    use base32_simd::ErrorKind;
+
+    assert_eq!(ZBASE32.charset(), b"ybndrfg8ejkmcpqxot1uwisza345h769");
+
+    let cases: &[(&[u8], usize, &str)] = &[
+        (&[], 0, ""),
+        (&[0x00], 1, "y"),
+        (&[0x80], 1, "o"),
+        (&[0x40], 2, "e"),
+        (&[0xc0], 2, "a"),
+        (&[0x00, 0x00], 10, "yy"),
+        (&[0x80, 0x80], 10, "on"),
+        (&[0x8b, 0x88, 0x80], 20, "tqre"),
+        (&[0xf0, 0xbf, 0xc7], 24, "6n9hq"),
+        (&[0xd4, 0x7a, 0x04], 24, "4t7ye"),
+        (&[0xf5, 0x57, 0xbb, 0x0c], 30, "6im5sd"),
+    ];
+    for &(bytes, bits, encoded) in cases {
+        assert_eq!(zbase32::encode_bits_to_string(bytes, bits).unwrap(), encoded);
+        assert_eq!(zbase32::decode_bits_to_vec(encoded, bits).unwrap(), bytes);
+    }
+
+    for n in [0, 1, 4, 5, 6, 26, 27, 100, 1000] {
+        let bytes = rand_bytes(n);
+        let encoded = ZBASE32.encode_to_string(&bytes);
+        assert_eq!(zbase32::encode_bits_to_string(&bytes, n * 8).unwrap(), encoded);
+        assert_eq!(zbase32::decode_bits_to_vec(&encoded, n * 8).unwrap(), bytes);
+
+        for cut in 1..8 {
+            if n == 0 {
+                break;
+            }
+            let bits = n * 8 - cut;
+            let encoded = zbase32::encode_bits_to_string(&bytes, bits).unwrap();
+            assert_eq!(encoded.len(), zbase32::encoded_length_bits(bits));
+
+            let mut expected = bytes.clone();
+            *expected.last_mut().unwrap() &= 0xff << cut;
+            assert_eq!(zbase32::decode_bits_to_vec(&encoded, bits).unwrap(), expected);
+        }
+    }
+
+    let err = zbase32::encode_bits_to_string([0xff], 9).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidLength);
+    let err = zbase32::decode_bits_to_vec("yyy", 10).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidLength);
+    let err = zbase32::decode_bits_to_vec("y0", 10).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidCharacter { offset: 1, byte: b'0' });
+    let err = zbase32::decode_bits_to_vec("yb", 9).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidCharacter { offset: 1, byte: b'b' });
+    let err = zbase32::decode_bits_to_vec("yyyyyyyYyy", 50).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidCharacter { offset: 7, byte: b'Y' });
+    let err = ZBASE32.decode_to_vec("PB1SA5DX").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidCharacter { offset: 0, byte: b'P' });
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn case_insensitive() {
+    use base32_simd::ErrorKind;
+
+    // the variants hold their charset and select constant tables
+    assert_eq!(core::mem::size_of::<Base32>(), 36);
+
+    assert_eq!(BASE32.lowercase().charset(), b"abcdefghijklmnopqrstuvwxyz234567");
+    assert_eq!(BASE32HEX.lowercase().charset(), b"0123456789abcdefghijklmnopqrstuv");
+    assert_eq!(BASE32.case_insensitive().charset(), BASE32.charset());
+    assert_eq!(CROCKFORD.lowercase().charset(), b"0123456789abcdefghjkmnpqrstvwxyz");
+
+    assert_eq!(BASE32.lowercase().encode_to_string("foobar"), "mzxw6ytboi======");
+    assert_eq!(BASE32HEX.lowercase().encode_to_string("foobar"), "cpnmuoj1e8======");
+    assert_eq!(BASE32.case_insensitive().encode_to_string("foobar"), "MZXW6YTBOI======");
+
+    let err = BASE32.decode_to_vec("mzxw6ytboi======").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidCharacter { offset: 0, byte: b'm' });
+
+    // the cases of a mixed-case charset are distinct characters
+    const WORD_SAFE: Base32 = Base32::custom(b"23456789CFGHJMPQRVWXcfghjmpqrvwx", false);
+    #[cfg(not(target_arch = "wasm32"))]
+    assert!(std::panic::catch_unwind(|| WORD_SAFE.lowercase()).is_err());
+
+    for n in [0, 1, 4, 5, 6, 26, 27, 100, 1000] {
+        let bytes = rand_bytes(n);
+
+        let encoded = WORD_SAFE.encode_to_string(&bytes);
+        let base32 = WORD_SAFE.case_insensitive();
+        assert_eq!(base32.encode_to_string(&bytes), encoded);
+        assert!(base32.check(encoded.as_bytes()).is_ok());
+        assert_eq!(base32.decode_to_vec(&encoded).unwrap(), bytes);
+        assert_eq!(base32.forgiving_decode_to_vec(&encoded).unwrap(), bytes);
+
+        for (upper, base32) in [
+            (BASE32, BASE32.lowercase()),
+            (BASE32, BASE32.case_insensitive()),
+            (BASE32HEX_NO_PAD, BASE32HEX_NO_PAD.lowercase()),
+            (BASE32HEX_NO_PAD, BASE32HEX_NO_PAD.case_insensitive()),
+            (ZBASE32, ZBASE32.case_insensitive()),
+        ] {
+            let encoded = upper.encode_to_string(&bytes);
+            let lower = encoded.to_ascii_lowercase();
+            let mixed: String = encoded
+                .chars()
+                .enumerate()
+                .map(|(i, c)| {
+                    if i % 3 == 0 {
+                        c.to_ascii_lowercase()
+                    } else {
+                        c.to_ascii_uppercase()
+                    }
+                })
+                .collect();
+
+            for s in [&encoded, &lower, &mixed] {
+                assert!(base32.check(s.as_bytes()).is_ok());
+                assert_eq!(base32.decode_to_vec(s).unwrap(), bytes);
+
+                let mut buf = s.clone().into_bytes();
+                assert_eq!(base32.decode_inplace(&mut buf).unwrap(), bytes);
+            }
+
+            if n >= 100 {
+                let mut invalid = lower.into_bytes();
+                invalid[70] = b'!';
+                let err = base32.decode_to_vec(&invalid).unwrap_err();
+                assert_eq!(err.kind(), ErrorKind::InvalidCharacter { offset: 70, byte: b'!' });
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn forgiving() {
+    use base32_simd::ErrorKind;
+
+    let base32 = BASE32.case_insensitive();
+    let expected = b"Hello!\xde\xad\xbe\xef";
+    for src in [
+        "JBSWY3DPEHPK3PXP",
+        "JBSW Y3DP EHPK 3PXP",
+        "jbsw-y3dp-ehpk-3pxp\n",
+        " \tJBSWY3dp\r\nEHPK3pxp ",
+    ] {
+        assert_eq!(base32.forgiving_decode_to_vec(src).unwrap(), expected);
+        let mut buf = src.as_bytes().to_owned();
+        assert_eq!(base32.forgiving_decode_inplace(&mut buf).unwrap(), expected);
+    }
+
+    for src in ["MZXW6===", "MZXW6", "MZXW 6===", "MZ-XW6", "MZXW6= ==\n"] {
+        assert_eq!(BASE32_NO_PAD.forgiving_decode_to_vec(src).unwrap(), b"foo");
+        assert_eq!(BASE32.forgiving_decode_to_vec(src).unwrap(), b"foo");
+    }
+
+    let separators = [" ", "\n", "\r\n", "\t", "-", "    "];
+    for n in [0, 1, 4, 5, 6, 26, 27, 100, 400, 1000] {
+        let bytes = rand_bytes(n);
+        for (base32, padded) in [
+            (BASE32, BASE32),
+            (BASE32HEX_NO_PAD, BASE32HEX),
+            (BASE32.lowercase(), BASE32),
+            (CROCKFORD, CROCKFORD),
+            (ZBASE32, ZBASE32),
+        ] {
+            let encoded = padded.encode_to_string(&bytes);
+            let unpadded = encoded.trim_end_matches('=');
+
+            for src in [encoded.as_str(), unpadded] {
+                let mut spaced = String::new();
+                for (i, c) in src.chars().enumerate() {
+                    if i % 5 == 0 {
+                        spaced.push_str(separators[i / 5 % separators.len()]);
+                    }
+                    spaced.push(c);
+                }
+                spaced.push('\n');
+
+                for s in [src, spaced.as_str()] {
+                    assert_eq!(base32.forgiving_decode_to_vec(s).unwrap(), bytes);
+                    let mut buf = s.as_bytes().to_owned();
+                    assert_eq!(base32.forgiving_decode_inplace(&mut buf).unwrap(), bytes);
+                }
+
+                if n > 0 {
+                    let mut buf = vec![0u8; n - 1];
+                    let err = base32.forgiving_decode(spaced.as_bytes(), buf.as_out()).unwrap_err();
+                    assert_eq!(err.kind(), ErrorKind::BufferTooSmall);
+                }
+
+                if n >= 400 {
+                    let mut invalid = spaced.clone().into_bytes();
+                    let offset = invalid.len() - 300;
+                    invalid[offset] = b'!';
+                    let err = base32.forgiving_decode_to_vec(&invalid).unwrap_err();
+                    assert_eq!(err.kind(), ErrorKind::InvalidCharacter { offset, byte: b'!' });
+                }
+            }
+        }
+    }
+
+    let err = BASE32.forgiving_decode_to_vec("MZXW 6YR").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidCharacter { offset: 7, byte: b'R' });
+    let err = BASE32.forgiving_decode_to_vec("MZX").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidLength);
+    let err = BASE32.forgiving_decode_to_vec("MZ=XW6Y=").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidPadding { offset: 2 });
+    let err = BASE32.forgiving_decode_to_vec("MY== MZXW\n").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidPadding { offset: 2 });
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn custom() {
+    use base32_simd::ErrorKind;
+
+    const RFC4648: Base32 = Base32::custom(b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567", true);
+    const BECH32: Base32 = Base32::custom(b"qpzry9x8gf2tvdw0s3jn54khce6mua7l", false);
+    const NIX: Base32 = Base32::custom(b"0123456789abcdfghijklmnpqrsvwxyz", false);
+    const WORD_SAFE: Base32 = Base32::custom(b"23456789CFGHJMPQRVWXcfghjmpqrvwx", false);
+    const NIX_UPPERCASE: Base32 = Base32::custom(b"0123456789ABCDFGHIJKLMNPQRSVWXYZ", false);
+
+    assert_eq!(BECH32.charset(), b"qpzry9x8gf2tvdw0s3jn54khce6mua7l");
+    assert_eq!(NIX_UPPERCASE.lowercase().charset(), NIX.charset());
+
+    let translate = |src: &str, from: &[u8; 32], to: &[u8; 32]| -> String {
+        src.bytes()
+            .map(|c| match from.iter().position(|&x| x == c) {
+                Some(i) => to[i] as char,
+                None => c as char,
+            })
+            .collect()
+    };
+
+    for n in [0, 1, 4, 5, 6, 26, 27, 100, 1000] {
+        let bytes = rand_bytes(n);
+        let expected = BASE32.encode_to_string(&bytes);
+
+        assert_eq!(RFC4648.encode_to_string(&bytes), expected);
+        assert_eq!(RFC4648.decode_to_vec(&expected).unwrap(), bytes);
+
+        let unpadded = BASE32_NO_PAD.encode_to_string(&bytes);
+        for base32 in [
+            BECH32,
+            NIX,
+            WORD_SAFE,
+            BECH32.case_insensitive(),
+            NIX.lowercase(),
+            NIX_UPPERCASE.lowercase(),
+        ] {
+            let encoded = translate(&unpadded, BASE32.charset(), base32.charset());
+            assert_eq!(base32.encode_to_string(&bytes), encoded);
+            assert!(base32.check(encoded.as_bytes()).is_ok());
+            assert_eq!(base32.decode_to_vec(&encoded).unwrap(), bytes);
+            assert_eq!(base32.forgiving_decode_to_vec(&encoded).unwrap(), bytes);
+        }
+
+        let encoded = BECH32.encode_to_string(&bytes);
+        assert_eq!(
+            BECH32
+                .case_insensitive()
+                .decode_to_vec(encoded.to_ascii_uppercase())
+                .unwrap(),
+            bytes
+        );
+        if n >= 100 {
+            let mut invalid = encoded.into_bytes();
+            invalid[50] = b'b';
+            let err = BECH32.decode_to_vec(&invalid).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidCharacter { offset: 50, byte: b'b' });
+        }
+    }
+
+    // the cases of a mixed-case charset are distinct characters
+    assert_eq!(WORD_SAFE.case_insensitive().charset(), WORD_SAFE.charset());
+    assert_eq!(WORD_SAFE.case_insensitive().decode_to_vec("Cc").unwrap(), [0x45]);
+    assert_eq!(WORD_SAFE.case_insensitive().decode_to_vec("CC").unwrap(), [0x42]);
+    let err = WORD_SAFE.decode_to_vec("C2a").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidLength);
+    let err = WORD_SAFE.decode_to_vec("C2a4").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidCharacter { offset: 2, byte: b'a' });
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn custom_separators() {
+    // the forgiving decoding functions would remove these characters
+    for c in [b'-', b'=', b' ', b'\t', b'\n', b'\x0c', b'\r'] {
+        let mut charset = *b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+        charset[31] = c;
+        assert!(std::panic::catch_unwind(|| Base32::custom(&charset, false)).is_err());
+    }
+}
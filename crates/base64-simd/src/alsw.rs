@@ -122,6 +122,42 @@ impl BcryptAlsw {
 
 vsimd::impl_alsw!(BcryptAlsw);
 
+struct CryptAlsw;
+
+impl CryptAlsw {
+    #[inline]
+    const fn decode(c: u8) -> u8 {
+        match c {
+            b'.' => 0,
+            b'/' => 1,
+            b'0'..=b'9' => c - b'0' + 2,
+            b'A'..=b'Z' => c - b'A' + 12,
+            b'a'..=b'z' => c - b'a' + 38,
+            _ => 0xff,
+        }
+    }
+
+    #[inline]
+    const fn check_hash(i: u8) -> u8 {
+        match i {
+            0 => 5,
+            1..=9 => 2,
+            0xA => 4,
+            0xB => 6,
+            0xC..=0xE => 8,
+            0xF => 6,
+            _ => unreachable!(),
+        }
+    }
+
+    #[inline]
+    const fn decode_hash(_: u8) -> u8 {
+        0x01
+    }
+}
+
+vsimd::impl_alsw!(CryptAlsw);
+
 pub const STANDARD_ALSW_CHECK_X2: AlswLut<V256> = StandardAlsw::check_lut().x2();
 pub const STANDARD_ALSW_DECODE_X2: AlswLut<V256> = StandardAlsw::decode_lut().x2();
 
@@ -131,6 +167,9 @@ pub const URL_SAFE_ALSW_DECODE_X2: AlswLut<V256> = UrlSafeAlsw::decode_lut().x2(
 pub const BCRYPT_ALSW_CHECK_X2: AlswLut<V256> = BcryptAlsw::check_lut().x2();
 pub const BCRYPT_ALSW_DECODE_X2: AlswLut<V256> = BcryptAlsw::decode_lut().x2();
 
+pub const CRYPT_ALSW_CHECK_X2: AlswLut<V256> = CryptAlsw::check_lut().x2();
+pub const CRYPT_ALSW_DECODE_X2: AlswLut<V256> = CryptAlsw::decode_lut().x2();
+
 #[cfg(test)]
 mod algorithm {
     use super::*;
@@ -148,4 +187,18 @@ mod algorithm {
         UrlSafeAlsw::test_check();
         UrlSafeAlsw::test_decode();
     }
+
+    #[test]
+    #[ignore]
+    fn bcrypt_alsw() {
+        BcryptAlsw::test_check();
+        BcryptAlsw::test_decode();
+    }
+
+    #[test]
+    #[ignore]
+    fn crypt_alsw() {
+        CryptAlsw::test_check();
+        CryptAlsw::test_decode();
+    }
 }
@@ -1,11 +1,13 @@
-use crate::alsw::{STANDARD_ALSW_CHECK_X2, URL_SAFE_ALSW_CHECK_X2};
-use crate::decode::{decode_ascii4, decode_ascii8, decode_extra};
+use crate::alsw::{BCRYPT_ALSW_CHECK_X2, CRYPT_ALSW_CHECK_X2, STANDARD_ALSW_CHECK_X2, URL_SAFE_ALSW_CHECK_X2};
+use crate::decode::{decode_ascii4, decode_ascii8, decode_crypt, decode_extra};
 use crate::decode::{BCRYPT_DECODE_TABLE, STANDARD_DECODE_TABLE, URL_SAFE_DECODE_TABLE};
 use crate::{Config, Error, Kind};
 
 use vsimd::alsw::AlswLut;
+use vsimd::is_isa_type;
+use vsimd::isa::{InstructionSet, SSE2};
 use vsimd::vector::V256;
-use vsimd::SIMD256;
+use vsimd::{SIMD128, SIMD256};
 
 use core::ptr::null_mut;
 
@@ -18,6 +20,7 @@ pub(crate) unsafe fn check_fallback(mut src: *const u8, mut n: usize, config: Co
         Kind::Standard => STANDARD_DECODE_TABLE.as_ptr(),
         Kind::UrlSafe => URL_SAFE_DECODE_TABLE.as_ptr(),
         Kind::Bcrypt => BCRYPT_DECODE_TABLE.as_ptr(),
+        Kind::Crypt => return decode_crypt::<false>(src, null_mut(), n, forgiving),
     };
 
     unsafe {
@@ -45,12 +48,17 @@ pub(crate) unsafe fn check_simd<S: SIMD256>(
     mut n: usize,
     config: Config,
 ) -> Result<(), Error> {
+    if cfg!(any(target_arch = "x86", target_arch = "x86_64")) && is_isa_type!(S, SSE2) {
+        return check_simd_sse2(SSE2::new(), src, n, config);
+    }
+
     let kind = config.kind;
 
     let check_lut = match kind {
         Kind::Standard => STANDARD_ALSW_CHECK_X2,
         Kind::UrlSafe => URL_SAFE_ALSW_CHECK_X2,
-        Kind::Bcrypt => unreachable!(),
+        Kind::Bcrypt => BCRYPT_ALSW_CHECK_X2,
+        Kind::Crypt => CRYPT_ALSW_CHECK_X2,
     };
 
     unsafe {
@@ -67,6 +75,24 @@ pub(crate) unsafe fn check_simd<S: SIMD256>(
     }
 }
 
+#[inline(always)]
+unsafe fn check_simd_sse2(s: SSE2, mut src: *const u8, mut n: usize, config: Config) -> Result<(), Error> {
+    let ranges = crate::sse2::decode_ranges_of(config.kind);
+
+    unsafe {
+        // n*3/4 >= 12+1
+        while n >= 18 {
+            let x = s.v128_load_unaligned(src);
+            let (_, is_valid) = crate::sse2::decode_values(s, x, ranges);
+            ensure!(is_valid);
+            src = src.add(16);
+            n -= 16;
+        }
+
+        check_fallback(src, n, config)
+    }
+}
+
 #[inline(always)]
 fn check_ascii32<S: SIMD256>(s: S, x: V256, check: AlswLut<V256>) -> bool {
     vsimd::alsw::check_ascii_xn(s, x, check)
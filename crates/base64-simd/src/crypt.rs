@@ -0,0 +1,127 @@
+use crate::{AsOut, Error, Out, CRYPT};
+
+const MD5_ORDER: [u8; 16] = [
+    12, 6, 0, 13, 7, 1, 14, 8, 2, 15, 9, 3, 5, 10, 4, 11, //
+];
+
+const SHA256_ORDER: [u8; 32] = [
+    20, 10, 0, 11, 1, 21, 2, 22, 12, 23, 13, 3, 14, 4, 24, 5, //
+    25, 15, 26, 16, 6, 17, 7, 27, 8, 28, 18, 29, 19, 9, 30, 31, //
+];
+
+const SHA512_ORDER: [u8; 64] = [
+    42, 21, 0, 1, 43, 22, 23, 2, 44, 45, 24, 3, 4, 46, 25, 26, //
+    5, 47, 48, 27, 6, 7, 49, 28, 29, 8, 50, 51, 30, 9, 10, 52, //
+    31, 32, 11, 53, 54, 33, 12, 13, 55, 34, 35, 14, 56, 57, 36, 15, //
+    16, 58, 37, 38, 17, 59, 60, 39, 18, 19, 61, 40, 41, 20, 62, 63, //
+];
+
+#[inline(always)]
+fn permute<const N: usize>(src: &[u8; N], order: &[u8; N]) -> [u8; N] {
+    let mut buf = [0; N];
+    for i in 0..N {
+        buf[i] = src[order[i] as usize];
+    }
+    buf
+}
+
+#[inline(always)]
+fn unpermute<const N: usize>(src: &[u8; N], order: &[u8; N]) -> [u8; N] {
+    let mut buf = [0; N];
+    for i in 0..N {
+        buf[order[i] as usize] = src[i];
+    }
+    buf
+}
+
+#[inline(always)]
+fn encode_digest<'d, const N: usize, const M: usize>(
+    digest: &[u8; N],
+    mut dst: Out<'d, [u8; M]>,
+    order: &[u8; N],
+) -> &'d mut [u8; M] {
+    let buf = permute(digest, order);
+    unsafe {
+        let dst = dst.as_mut_ptr().cast::<u8>();
+        crate::multiversion::encode::auto(buf.as_ptr(), N, dst, CRYPT.config);
+        &mut *dst.cast::<[u8; M]>()
+    }
+}
+
+#[inline(always)]
+fn decode_digest<'d, const N: usize, const M: usize>(
+    src: &[u8],
+    mut dst: Out<'d, [u8; N]>,
+    order: &[u8; N],
+) -> Result<&'d mut [u8; N], Error> {
+    ensure!(src.len() == M);
+
+    let mut buf = [0; N];
+    CRYPT.decode(src, buf.as_mut_slice().as_out())?;
+
+    unsafe {
+        let dst = dst.as_mut_ptr();
+        dst.write(unpermute(&buf, order));
+        Ok(&mut *dst)
+    }
+}
+
+/// Encodes an MD5-crypt (`$1$`) digest to its crypt(3) hash string.
+///
+/// The digest bytes are rearranged in the order used by MD5-crypt before encoding with [`CRYPT`].
+#[inline]
+#[must_use]
+pub fn crypt_encode_md5<'d>(digest: &[u8; 16], dst: Out<'d, [u8; 22]>) -> &'d mut [u8; 22] {
+    encode_digest(digest, dst, &MD5_ORDER)
+}
+
+/// Decodes an MD5-crypt (`$1$`) hash string to its digest.
+///
+/// # Errors
+/// This function returns `Err` if
+/// + the length of `src` is not 22.
+/// + the content of `src` is invalid.
+#[inline]
+pub fn crypt_decode_md5<'d>(src: &[u8], dst: Out<'d, [u8; 16]>) -> Result<&'d mut [u8; 16], Error> {
+    decode_digest::<16, 22>(src, dst, &MD5_ORDER)
+}
+
+/// Encodes a SHA256-crypt (`$5$`) digest to its crypt(3) hash string.
+///
+/// The digest bytes are rearranged in the order used by SHA256-crypt before encoding with [`CRYPT`].
+#[inline]
+#[must_use]
+pub fn crypt_encode_sha256<'d>(digest: &[u8; 32], dst: Out<'d, [u8; 43]>) -> &'d mut [u8; 43] {
+    encode_digest(digest, dst, &SHA256_ORDER)
+}
+
+/// Decodes a SHA256-crypt (`$5$`) hash string to its digest.
+///
+/// # Errors
+/// This function returns `Err` if
+/// + the length of `src` is not 43.
+/// + the content of `src` is invalid.
+#[inline]
+pub fn crypt_decode_sha256<'d>(src: &[u8], dst: Out<'d, [u8; 32]>) -> Result<&'d mut [u8; 32], Error> {
+    decode_digest::<32, 43>(src, dst, &SHA256_ORDER)
+}
+
+/// Encodes a SHA512-crypt (`$6$`) digest to its crypt(3) hash string.
+///
+/// The digest bytes are rearranged in the order used by SHA512-crypt before encoding with [`CRYPT`].
+#[inline]
+#[must_use]
+pub fn crypt_encode_sha512<'d>(digest: &[u8; 64], dst: Out<'d, [u8; 86]>) -> &'d mut [u8; 86] {
+    encode_digest(digest, dst, &SHA512_ORDER)
+}
+
+/// Decodes a SHA512-crypt (`$6$`) hash string to its digest.
+///
+/// # Errors
+/// This function returns `Err` if
+/// + the length of `src` is not 86.
+/// + the content of `src` is invalid.
+#[inline]
+pub fn crypt_decode_sha512<'d>(src: &[u8], dst: Out<'d, [u8; 64]>) -> Result<&'d mut [u8; 64], Error> {
+    decode_digest::<64, 86>(src, dst, &SHA512_ORDER)
+}
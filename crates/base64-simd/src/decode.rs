@@ -1,15 +1,17 @@
 use crate::alsw::{BCRYPT_ALSW_CHECK_X2, BCRYPT_ALSW_DECODE_X2, STANDARD_ALSW_CHECK_X2, URL_SAFE_ALSW_CHECK_X2};
+use crate::alsw::{CRYPT_ALSW_CHECK_X2, CRYPT_ALSW_DECODE_X2};
 use crate::alsw::{STANDARD_ALSW_DECODE_X2, URL_SAFE_ALSW_DECODE_X2};
-use crate::{Config, Error, Extra, Kind, BCRYPT_CHARSET};
+use crate::{Config, Error, Extra, Kind, BCRYPT_CHARSET, CRYPT_CHARSET};
 use crate::{STANDARD_CHARSET, URL_SAFE_CHARSET};
 
 use vsimd::alsw::AlswLut;
-use vsimd::isa::{NEON, SSSE3, WASM128};
+use vsimd::is_isa_type;
+use vsimd::isa::{InstructionSet, NEON, SSE2, SSSE3, WASM128};
 use vsimd::mask::u8x32_highbit_any;
 use vsimd::matches_isa;
 use vsimd::tools::{read, write};
 use vsimd::vector::V256;
-use vsimd::SIMD256;
+use vsimd::{SIMD128, SIMD256};
 
 use core::ops::Not;
 
@@ -26,9 +28,13 @@ const fn decode_table(charset: &'static [u8; 64]) -> [u8; 256] {
 pub const STANDARD_DECODE_TABLE: &[u8; 256] = &decode_table(STANDARD_CHARSET);
 pub const URL_SAFE_DECODE_TABLE: &[u8; 256] = &decode_table(URL_SAFE_CHARSET);
 pub const BCRYPT_DECODE_TABLE: &[u8; 256] = &decode_table(BCRYPT_CHARSET);
+pub const CRYPT_DECODE_TABLE: &[u8; 256] = &decode_table(CRYPT_CHARSET);
 
 #[inline(always)]
-pub(crate) fn decoded_length(src: &[u8], config: Config) -> Result<(usize, usize), Error> {
+pub(crate) fn decoded_length<T: Copy + PartialEq + From<u8>>(
+    src: &[T],
+    config: Config,
+) -> Result<(usize, usize), Error> {
     if src.is_empty() {
         return Ok((0, 0));
     }
@@ -39,8 +45,8 @@ pub(crate) fn decoded_length(src: &[u8], config: Config) -> Result<(usize, usize
         let count_pad = || {
             let last1 = *src.get_unchecked(len - 1);
             let last2 = *src.get_unchecked(len - 2);
-            if last1 == b'=' {
-                if last2 == b'=' {
+            if last1 == T::from(b'=') {
+                if last2 == T::from(b'=') {
                     2
                 } else {
                     1
@@ -172,6 +178,97 @@ pub unsafe fn decode_extra<const WRITE: bool>(
     Ok(())
 }
 
+#[inline(always)]
+unsafe fn decode_ascii4_le<const WRITE: bool>(src: *const u8, dst: *mut u8, table: *const u8) -> Result<(), Error> {
+    let mut y: u32 = 0;
+    let mut flag = 0;
+
+    let mut i = 0;
+    while i < 4 {
+        let x = read(src, i);
+        let bits = read(table, x as usize);
+        flag |= bits;
+
+        if WRITE {
+            y |= (bits as u32) << (i * 6);
+        }
+
+        i += 1;
+    }
+
+    if WRITE {
+        let y = y.to_le_bytes();
+        write(dst, 0, y[0]);
+        write(dst, 1, y[1]);
+        write(dst, 2, y[2]);
+    }
+
+    ensure!(flag != 0xff);
+    Ok(())
+}
+
+#[inline(always)]
+unsafe fn decode_extra_le<const WRITE: bool>(
+    extra: usize,
+    src: *const u8,
+    dst: *mut u8,
+    table: *const u8,
+    forgiving: bool,
+) -> Result<(), Error> {
+    match extra {
+        0 => {}
+        1 => core::hint::unreachable_unchecked(),
+        2 => {
+            let [x1, x2] = src.cast::<[u8; 2]>().read();
+
+            let y1 = read(table, x1 as usize);
+            let y2 = read(table, x2 as usize);
+            ensure!((y1 | y2) != 0xff && (forgiving || y2 < 0x04));
+
+            if WRITE {
+                write(dst, 0, y1 | (y2 << 6));
+            }
+        }
+        3 => {
+            let [x1, x2, x3] = src.cast::<[u8; 3]>().read();
+
+            let y1 = read(table, x1 as usize);
+            let y2 = read(table, x2 as usize);
+            let y3 = read(table, x3 as usize);
+            ensure!((y1 | y2 | y3) != 0xff && (forgiving || y3 < 0x10));
+
+            if WRITE {
+                write(dst, 0, y1 | (y2 << 6));
+                write(dst, 1, (y2 >> 2) | (y3 << 4));
+            }
+        }
+        _ => core::hint::unreachable_unchecked(),
+    }
+    Ok(())
+}
+
+#[inline]
+pub(crate) unsafe fn decode_crypt<const WRITE: bool>(
+    mut src: *const u8,
+    mut dst: *mut u8,
+    mut n: usize,
+    forgiving: bool,
+) -> Result<(), Error> {
+    let table = CRYPT_DECODE_TABLE.as_ptr();
+
+    let end = src.add(n / 4 * 4);
+    while src < end {
+        decode_ascii4_le::<WRITE>(src, dst, table)?;
+        src = src.add(4);
+        if WRITE {
+            dst = dst.add(3);
+        }
+    }
+    n %= 4;
+
+    decode_extra_le::<WRITE>(n, src, dst, table, forgiving)
+}
+
 #[inline]
 pub(crate) unsafe fn decode_fallback(
     mut src: *const u8,
@@ -186,6 +283,7 @@ pub(crate) unsafe fn decode_fallback(
         Kind::Standard => STANDARD_DECODE_TABLE.as_ptr(),
         Kind::UrlSafe => URL_SAFE_DECODE_TABLE.as_ptr(),
         Kind::Bcrypt => BCRYPT_DECODE_TABLE.as_ptr(),
+        Kind::Crypt => return decode_crypt::<true>(src, dst, n, forgiving),
     };
 
     // n*3/4 >= 6+2
@@ -215,12 +313,21 @@ pub(crate) unsafe fn decode_simd<S: SIMD256>(
     mut n: usize,
     config: Config,
 ) -> Result<(), Error> {
+    if cfg!(any(target_arch = "x86", target_arch = "x86_64")) && is_isa_type!(S, SSE2) {
+        return decode_simd_sse2(SSE2::new(), src, dst, n, config);
+    }
+
     let kind = config.kind;
 
+    if let Kind::Crypt = kind {
+        return decode_crypt_simd(s, src, dst, n, config);
+    }
+
     let (check_lut, decode_lut) = match kind {
         Kind::Standard => (STANDARD_ALSW_CHECK_X2, STANDARD_ALSW_DECODE_X2),
         Kind::UrlSafe => (URL_SAFE_ALSW_CHECK_X2, URL_SAFE_ALSW_DECODE_X2),
         Kind::Bcrypt => (BCRYPT_ALSW_CHECK_X2, BCRYPT_ALSW_DECODE_X2),
+        Kind::Crypt => unreachable!(),
     };
 
     // n*3/4 >= 24+4
@@ -240,6 +347,61 @@ pub(crate) unsafe fn decode_simd<S: SIMD256>(
     decode_fallback(src, dst, n, config)
 }
 
+#[inline(always)]
+unsafe fn decode_simd_sse2(
+    s: SSE2,
+    mut src: *const u8,
+    mut dst: *mut u8,
+    mut n: usize,
+    config: Config,
+) -> Result<(), Error> {
+    let kind = config.kind;
+    let ranges = crate::sse2::decode_ranges_of(kind);
+
+    // n*3/4 >= 12+1
+    while n >= 18 {
+        let x = s.v128_load_unaligned(src);
+        let (values, is_valid) = crate::sse2::decode_values(s, x, ranges);
+        ensure!(is_valid);
+
+        match kind {
+            Kind::Crypt => crate::sse2::store_bytes12::<false>(s, dst, crate::sse2::merge_bits_le(s, values)),
+            _ => crate::sse2::store_bytes12::<true>(s, dst, crate::sse2::merge_bits(s, values)),
+        }
+
+        src = src.add(16);
+        dst = dst.add(12);
+        n -= 16;
+    }
+
+    decode_fallback(src, dst, n, config)
+}
+
+#[inline(always)]
+unsafe fn decode_crypt_simd<S: SIMD256>(
+    s: S,
+    mut src: *const u8,
+    mut dst: *mut u8,
+    mut n: usize,
+    config: Config,
+) -> Result<(), Error> {
+    // n*3/4 >= 24+4
+    while n >= 38 {
+        let x = s.v256_load_unaligned(src);
+        let y = try_!(decode_crypt_ascii32(s, x));
+
+        let (y1, y2) = y.to_v128x2();
+        s.v128_store_unaligned(dst, y1);
+        s.v128_store_unaligned(dst.add(12), y2);
+
+        src = src.add(32);
+        dst = dst.add(24);
+        n -= 32;
+    }
+
+    decode_crypt::<true>(src, dst, n, config.extra.forgiving())
+}
+
 #[inline(always)]
 fn merge_bits_x2<S: SIMD256>(s: S, x: V256) -> V256 {
     // x : {00aaaaaa|00bbbbbb|00cccccc|00dddddd} x8
@@ -289,3 +451,42 @@ fn decode_ascii32<S: SIMD256>(s: S, x: V256, check: AlswLut<V256>, decode: AlswL
     ensure!(u8x32_highbit_any(s, c1).not());
     Ok(y)
 }
+
+#[inline(always)]
+fn merge_bits_le_x2<S: SIMD256>(s: S, x: V256) -> V256 {
+    // x : {00aaaaaa|00bbbbbb|00cccccc|00dddddd} x8
+
+    let y = if matches_isa!(S, SSSE3) {
+        let m1 = s.u16x16_splat(u16::from_le_bytes([0x01, 0x40]));
+        let x1 = s.i16x16_maddubs(x, m1);
+        // x1: {bbaaaaaa|0000bbbb|ddcccccc|0000dddd} x8
+
+        let m2 = s.u32x8_splat(u32::from_le_bytes([0x01, 0x00, 0x00, 0x10]));
+        s.i16x16_madd(x1, m2)
+        // {bbaaaaaa|ccccbbbb|ddddddcc|00000000} x8
+    } else if matches_isa!(S, NEON | WASM128) {
+        let x1 = s.v256_and(x, s.u32x8_splat(0x0000_003f));
+        let x2 = s.v256_and(s.u32x8_shr::<2>(x), s.u32x8_splat(0x0000_0fc0));
+        let x3 = s.v256_and(s.u32x8_shr::<4>(x), s.u32x8_splat(0x0003_f000));
+        let x4 = s.v256_and(s.u32x8_shr::<6>(x), s.u32x8_splat(0x00fc_0000));
+        s.v256_or(s.v256_or(x1, x2), s.v256_or(x3, x4))
+        // {bbaaaaaa|ccccbbbb|ddddddcc|00000000} x8
+    } else {
+        unreachable!()
+    };
+
+    const SHUFFLE: V256 = V256::double_bytes([
+        0x00, 0x01, 0x02, 0x04, 0x05, 0x06, 0x08, 0x09, //
+        0x0a, 0x0c, 0x0d, 0x0e, 0x80, 0x80, 0x80, 0x80, //
+    ]);
+    s.u8x16x2_swizzle(y, SHUFFLE)
+    // {AAAB|BBCC|CDDD|0000|EEEF|FFGG|GHHH|0000}
+}
+
+#[inline(always)]
+fn decode_crypt_ascii32<S: SIMD256>(s: S, x: V256) -> Result<V256, Error> {
+    let (c1, c2) = vsimd::alsw::decode_ascii_xn(s, x, CRYPT_ALSW_CHECK_X2, CRYPT_ALSW_DECODE_X2);
+    let y = merge_bits_le_x2(s, c2);
+    ensure!(u8x32_highbit_any(s, c1).not());
+    Ok(y)
+}
@@ -1,12 +1,45 @@
-use crate::{Config, Kind, BCRYPT_CHARSET};
+use crate::{Config, Kind, BCRYPT_CHARSET, CRYPT_CHARSET};
 use crate::{STANDARD_CHARSET, URL_SAFE_CHARSET};
 
-use vsimd::isa::{NEON, SSE2, WASM128};
+use vsimd::is_isa_type;
+use vsimd::isa::{InstructionSet, NEON, SSE2, WASM128};
 use vsimd::tools::{read, write};
 use vsimd::vector::{V128, V256};
 use vsimd::{matches_isa, POD};
 use vsimd::{Scalable, SIMD128, SIMD256};
 
+/// Output code unit of encoding: `u8` for bytes and `u16` for UTF-16.
+pub(crate) trait CodeUnit: Copy + From<u8> {
+    unsafe fn store_v128<S: SIMD256>(s: S, dst: *mut Self, y: V128);
+    unsafe fn store_v256<S: SIMD256>(s: S, dst: *mut Self, y: V256);
+}
+
+impl CodeUnit for u8 {
+    #[inline(always)]
+    unsafe fn store_v128<S: SIMD256>(s: S, dst: *mut Self, y: V128) {
+        s.v128_store_unaligned(dst, y);
+    }
+
+    #[inline(always)]
+    unsafe fn store_v256<S: SIMD256>(s: S, dst: *mut Self, y: V256) {
+        s.v256_store_unaligned(dst, y);
+    }
+}
+
+impl CodeUnit for u16 {
+    #[inline(always)]
+    unsafe fn store_v128<S: SIMD256>(s: S, dst: *mut Self, y: V128) {
+        s.v256_store_unaligned(dst.cast(), s.u16x16_from_u8x16(y));
+    }
+
+    #[inline(always)]
+    unsafe fn store_v256<S: SIMD256>(s: S, dst: *mut Self, y: V256) {
+        let (y0, y1) = y.to_v128x2();
+        Self::store_v128(s, dst, y0);
+        Self::store_v128(s, dst.add(16), y1);
+    }
+}
+
 #[inline(always)]
 pub(crate) const fn encoded_length_unchecked(len: usize, config: Config) -> usize {
     let extra = len % 3;
@@ -20,42 +53,42 @@ pub(crate) const fn encoded_length_unchecked(len: usize, config: Config) -> usiz
 }
 
 #[inline(always)]
-unsafe fn encode_bits24(src: *const u8, dst: *mut u8, charset: *const u8) {
+unsafe fn encode_bits24<T: CodeUnit>(src: *const u8, dst: *mut T, charset: *const u8) {
     let x = u32::from_be_bytes([0, read(src, 0), read(src, 1), read(src, 2)]);
     let mut i = 0;
     while i < 4 {
         let bits = (x >> (18 - i * 6)) & 0x3f;
         let y = read(charset, bits as usize);
-        write(dst, i, y);
+        write(dst, i, T::from(y));
         i += 1;
     }
 }
 
 #[inline(always)]
-unsafe fn encode_bits48(src: *const u8, dst: *mut u8, charset: *const u8) {
+unsafe fn encode_bits48<T: CodeUnit>(src: *const u8, dst: *mut T, charset: *const u8) {
     let x = u64::from_be_bytes(src.cast::<[u8; 8]>().read());
     let mut i = 0;
     while i < 8 {
         let bits = (x >> (58 - i * 6)) & 0x3f;
         let y = read(charset, bits as usize);
-        write(dst, i, y);
+        write(dst, i, T::from(y));
         i += 1;
     }
 }
 
 #[inline(always)]
-unsafe fn encode_extra(extra: usize, src: *const u8, dst: *mut u8, charset: *const u8, padding: bool) {
+unsafe fn encode_extra<T: CodeUnit>(extra: usize, src: *const u8, dst: *mut T, charset: *const u8, padding: bool) {
     match extra {
         0 => {}
         1 => {
             let x = read(src, 0);
             let y1 = read(charset, (x >> 2) as usize);
             let y2 = read(charset, ((x << 6) >> 2) as usize);
-            write(dst, 0, y1);
-            write(dst, 1, y2);
+            write(dst, 0, T::from(y1));
+            write(dst, 1, T::from(y2));
             if padding {
-                write(dst, 2, b'=');
-                write(dst, 3, b'=');
+                write(dst, 2, T::from(b'='));
+                write(dst, 3, T::from(b'='));
             }
         }
         2 => {
@@ -64,19 +97,65 @@ unsafe fn encode_extra(extra: usize, src: *const u8, dst: *mut u8, charset: *con
             let y1 = read(charset, (x1 >> 2) as usize);
             let y2 = read(charset, (((x1 << 6) >> 2) | (x2 >> 4)) as usize);
             let y3 = read(charset, ((x2 << 4) >> 2) as usize);
-            write(dst, 0, y1);
-            write(dst, 1, y2);
-            write(dst, 2, y3);
+            write(dst, 0, T::from(y1));
+            write(dst, 1, T::from(y2));
+            write(dst, 2, T::from(y3));
             if padding {
-                write(dst, 3, b'=');
+                write(dst, 3, T::from(b'='));
             }
         }
         _ => core::hint::unreachable_unchecked(),
     }
 }
 
+#[inline(always)]
+unsafe fn encode_bits24_le<T: CodeUnit>(src: *const u8, dst: *mut T, charset: *const u8) {
+    let x = u32::from_le_bytes([read(src, 0), read(src, 1), read(src, 2), 0]);
+    let mut i = 0;
+    while i < 4 {
+        let bits = (x >> (i * 6)) & 0x3f;
+        let y = read(charset, bits as usize);
+        write(dst, i, T::from(y));
+        i += 1;
+    }
+}
+
+#[inline(always)]
+unsafe fn encode_extra_le<T: CodeUnit>(extra: usize, src: *const u8, dst: *mut T, charset: *const u8) {
+    match extra {
+        0 => {}
+        1 => {
+            let x = read(src, 0);
+            write(dst, 0, T::from(read(charset, (x & 0x3f) as usize)));
+            write(dst, 1, T::from(read(charset, (x >> 6) as usize)));
+        }
+        2 => {
+            let x = u16::from_le_bytes([read(src, 0), read(src, 1)]);
+            write(dst, 0, T::from(read(charset, (x & 0x3f) as usize)));
+            write(dst, 1, T::from(read(charset, ((x >> 6) & 0x3f) as usize)));
+            write(dst, 2, T::from(read(charset, (x >> 12) as usize)));
+        }
+        _ => core::hint::unreachable_unchecked(),
+    }
+}
+
+#[inline]
+unsafe fn encode_crypt_fallback<T: CodeUnit>(mut src: *const u8, mut len: usize, mut dst: *mut T) {
+    let charset = CRYPT_CHARSET.as_ptr();
+
+    let end = src.add(len / 3 * 3);
+    while src < end {
+        encode_bits24_le(src, dst, charset);
+        src = src.add(3);
+        dst = dst.add(4);
+    }
+    len %= 3;
+
+    encode_extra_le(len, src, dst, charset);
+}
+
 #[inline]
-pub(crate) unsafe fn encode_fallback(mut src: *const u8, mut len: usize, mut dst: *mut u8, config: Config) {
+pub(crate) unsafe fn encode_fallback<T: CodeUnit>(mut src: *const u8, mut len: usize, mut dst: *mut T, config: Config) {
     let kind = config.kind;
     let padding = config.extra.padding();
 
@@ -84,6 +163,7 @@ pub(crate) unsafe fn encode_fallback(mut src: *const u8, mut len: usize, mut dst
         Kind::Standard => STANDARD_CHARSET.as_ptr(),
         Kind::UrlSafe => URL_SAFE_CHARSET.as_ptr(),
         Kind::Bcrypt => BCRYPT_CHARSET.as_ptr(),
+        Kind::Crypt => return encode_crypt_fallback(src, len, dst),
     };
 
     const L: usize = 4;
@@ -117,20 +197,29 @@ pub(crate) unsafe fn encode_fallback(mut src: *const u8, mut len: usize, mut dst
 }
 
 #[inline(always)]
-pub(crate) unsafe fn encode_simd<S: SIMD256>(
+pub(crate) unsafe fn encode_simd<S: SIMD256, T: CodeUnit>(
     s: S,
     mut src: *const u8,
     mut len: usize,
-    mut dst: *mut u8,
+    mut dst: *mut T,
     config: Config,
 ) {
+    if cfg!(any(target_arch = "x86", target_arch = "x86_64")) && is_isa_type!(S, SSE2) {
+        return encode_simd_sse2(SSE2::new(), src, len, dst, config);
+    }
+
     let kind = config.kind;
 
+    if let Kind::Crypt = kind {
+        return encode_crypt_simd(s, src, len, dst);
+    }
+
     if len >= (6 + 24 + 4) {
         let (charset, shift_lut) = match kind {
             Kind::Standard => (STANDARD_CHARSET.as_ptr(), STANDARD_ENCODING_SHIFT_X2),
             Kind::UrlSafe => (URL_SAFE_CHARSET.as_ptr(), URL_SAFE_ENCODING_SHIFT_X2),
             Kind::Bcrypt => (BCRYPT_CHARSET.as_ptr(), BCRYPT_ENCODING_SHIFT_X2),
+            Kind::Crypt => unreachable!(),
         };
 
         for _ in 0..2 {
@@ -143,7 +232,7 @@ pub(crate) unsafe fn encode_simd<S: SIMD256>(
         while len >= (24 + 4) {
             let x = s.v256_load_unaligned(src.sub(4));
             let y = encode_bytes24(s, x, shift_lut);
-            s.v256_store_unaligned(dst, y);
+            T::store_v256(s, dst, y);
             src = src.add(24);
             dst = dst.add(32);
             len -= 24;
@@ -155,11 +244,58 @@ pub(crate) unsafe fn encode_simd<S: SIMD256>(
             Kind::Standard => STANDARD_ENCODING_SHIFT,
             Kind::UrlSafe => URL_SAFE_ENCODING_SHIFT,
             Kind::Bcrypt => BCRYPT_ENCODING_SHIFT,
+            Kind::Crypt => unreachable!(),
         };
 
         let x = s.v128_load_unaligned(src);
         let y = encode_bytes12(s, x, shift_lut);
-        s.v128_store_unaligned(dst, y);
+        T::store_v128(s, dst, y);
+        src = src.add(12);
+        dst = dst.add(16);
+        len -= 12;
+    }
+
+    encode_fallback(src, len, dst, config);
+}
+
+#[inline(always)]
+unsafe fn encode_crypt_simd<S: SIMD256, T: CodeUnit>(s: S, mut src: *const u8, mut len: usize, mut dst: *mut T) {
+    if len >= (6 + 24 + 4) {
+        let charset = CRYPT_CHARSET.as_ptr();
+
+        for _ in 0..2 {
+            encode_bits24_le(src, dst, charset);
+            src = src.add(3);
+            dst = dst.add(4);
+            len -= 3;
+        }
+
+        while len >= (24 + 4) {
+            let x = s.v256_load_unaligned(src.sub(4));
+            let y = encode_crypt_bytes24(s, x);
+            T::store_v256(s, dst, y);
+            src = src.add(24);
+            dst = dst.add(32);
+            len -= 24;
+        }
+    }
+
+    encode_crypt_fallback(src, len, dst);
+}
+
+#[inline(always)]
+unsafe fn encode_simd_sse2<T: CodeUnit>(s: SSE2, mut src: *const u8, mut len: usize, mut dst: *mut T, config: Config) {
+    let kind = config.kind;
+    let steps = crate::sse2::encode_steps_of(kind);
+
+    while len >= 12 + 4 {
+        let x = crate::sse2::load_bytes12(s, src);
+        let values = match kind {
+            Kind::Crypt => crate::sse2::split_bits_le(s, x),
+            _ => crate::sse2::split_bits(s, x),
+        };
+        let y = crate::sse2::encode_values(s, values, steps);
+        T::store_v128(s, dst, y);
         src = src.add(12);
         dst = dst.add(16);
         len -= 12;
@@ -345,3 +481,52 @@ fn encode_bytes12<S: SIMD256>(s: S, x: V128, shift_lut: V128) -> V128 {
     encode_values(s, values, shift_lut)
     // {{ascii}} x16
 }
+
+#[inline(always)]
+fn split_bits_le_x2<S: SIMD256>(s: S, x: V256) -> V256 {
+    // x: {????|AAAB|BBCC|CDDD|EEEF|FFGG|GHHH|????}
+
+    const SHUFFLE: V256 = V256::from_bytes([
+        0x04, 0x05, 0x06, 0x80, 0x07, 0x08, 0x09, 0x80, //
+        0x0a, 0x0b, 0x0c, 0x80, 0x0d, 0x0e, 0x0f, 0x80, //
+        0x00, 0x01, 0x02, 0x80, 0x03, 0x04, 0x05, 0x80, //
+        0x06, 0x07, 0x08, 0x80, 0x09, 0x0a, 0x0b, 0x80, //
+    ]);
+
+    let x0 = s.u8x16x2_swizzle(x, SHUFFLE);
+    // x0: {bbaaaaaa|ccccbbbb|ddddddcc|00000000} x8
+
+    let x1 = s.v256_and(x0, s.u32x8_splat(0x0000_003f));
+    let x2 = s.v256_and(s.u32x8_shl::<2>(x0), s.u32x8_splat(0x0000_3f00));
+    let x3 = s.v256_and(s.u32x8_shl::<4>(x0), s.u32x8_splat(0x003f_0000));
+    let x4 = s.v256_and(s.u32x8_shl::<6>(x0), s.u32x8_splat(0x3f00_0000));
+
+    s.v256_or(s.v256_or(x1, x2), s.v256_or(x3, x4))
+    // {00aaaaaa|00bbbbbb|00cccccc|00dddddd} x8
+}
+
+#[inline(always)]
+fn encode_crypt_values<S: Scalable<V>, V: POD>(s: S, x: V) -> V {
+    // x: {00aaaaaa|00bbbbbb|00cccccc|00dddddd} xn
+
+    // 0~11     '.'         [./0-9]
+    // 12~37    '.' + 7     [A-Z]
+    // 38~63    '.' + 13    [a-z]
+
+    let x1 = s.and(s.i8xn_lt(s.u8xn_splat(11), x), s.u8xn_splat(7));
+    let x2 = s.and(s.i8xn_lt(s.u8xn_splat(37), x), s.u8xn_splat(6));
+    let shift = s.u8xn_add(s.u8xn_splat(b'.'), s.u8xn_add(x1, x2));
+    s.u8xn_add(x, shift)
+    // {{ascii}} xn
+}
+
+#[inline(always)]
+fn encode_crypt_bytes24<S: SIMD256>(s: S, x: V256) -> V256 {
+    // x: {????|AAAB|BBCC|CDDD|EEEF|FFGG|GHHH|????}
+
+    let values = split_bits_le_x2(s, x);
+    // values: {00aaaaaa|00bbbbbb|00cccccc|00dddddd} x8
+
+    encode_crypt_values(s, values)
+    // {{ascii}} x32
+}
@@ -1,14 +1,26 @@
-use crate::ascii::*;
 use crate::STANDARD_FORGIVING;
 use crate::{Error, Out};
 
-use vsimd::tools::slice_mut;
+use vsimd::ascii::remove_ascii_whitespace_fallback;
+use vsimd::tools::{slice_mut, slice_parts};
 
 use core::ptr::copy_nonoverlapping;
 
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
+#[inline(always)]
+fn find_non_ascii_whitespace(data: &[u8]) -> usize {
+    let (src, len) = slice_parts(data);
+    unsafe { crate::multiversion::find_non_ascii_whitespace::auto(src, len) }
+}
+
+#[inline(always)]
+fn remove_ascii_whitespace_inplace(data: &mut [u8]) -> &mut [u8] {
+    let pos = find_non_ascii_whitespace(data);
+    vsimd::ascii::remove_ascii_whitespace_inplace(data, pos)
+}
+
 /// Forgiving decodes a base64 string to bytes and writes inplace.
 ///
 /// This function uses the standard charset.
@@ -60,6 +72,30 @@ pub fn forgiving_decode<'d>(src: &[u8], mut dst: Out<'d, [u8]>) -> Result<&'d mu
     }
 }
 
+/// Forgiving decodes a base64 string in UTF-16 to bytes.
+///
+/// This function uses the standard charset.
+/// The code units are narrowed into `dst`, which is then decoded inplace.
+///
+/// See <https://infra.spec.whatwg.org/#forgiving-base64>
+///
+/// # Errors
+/// This function returns `Err` if
+/// + `src.len() > dst.len()`
+/// + the content of `src` is invalid
+///
+#[inline]
+pub fn forgiving_decode_utf16<'d>(src: &[u16], mut dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Error> {
+    ensure!(src.len() <= dst.len());
+
+    unsafe {
+        let len = src.len();
+        let dst = dst.as_mut_ptr();
+        crate::multiversion::narrow_ascii::auto(src.as_ptr(), len, dst)?;
+        forgiving_decode_inplace(slice_mut(dst, len))
+    }
+}
+
 /// Forgiving decodes a base64 string to bytes and returns a new [`Vec<u8>`](Vec).
 ///
 /// This function uses the standard charset.
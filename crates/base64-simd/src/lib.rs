@@ -53,10 +53,10 @@ mod error;
 pub use self::error::Error;
 
 mod alsw;
-mod ascii;
 mod check;
 mod decode;
 mod encode;
+mod sse2;
 
 mod multiversion;
 
@@ -69,6 +69,14 @@ mod parallel;
 mod forgiving;
 pub use self::forgiving::*;
 
+mod crypt;
+pub use self::crypt::*;
+
+mod spans;
+pub use self::spans::Spans;
+
+mod utf16;
+
 pub use outref::{AsOut, Out};
 
 // -----------------------------------------------------------------------------
@@ -84,6 +92,7 @@ use alloc::{string::String, vec::Vec};
 const STANDARD_CHARSET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
 const URL_SAFE_CHARSET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
 const BCRYPT_CHARSET: &[u8; 64] = b"./ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+const CRYPT_CHARSET: &[u8; 64] = b"./0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
 
 /// Base64 variant
 #[derive(Debug)]
@@ -96,6 +105,7 @@ enum Kind {
     Standard,
     UrlSafe,
     Bcrypt,
+    Crypt,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -182,6 +192,16 @@ pub const BCRYPT_NO_PAD: Base64 = Base64 {
     },
 };
 
+/// crypt(3) charset without padding.
+///
+/// This variant packs bits in little-endian order, as used by MD5-crypt, SHA256-crypt and SHA512-crypt.
+pub const CRYPT: Base64 = Base64 {
+    config: Config {
+        kind: Kind::Crypt,
+        extra: Extra::NoPad,
+    },
+};
+
 const STANDARD_FORGIVING: Base64 = Base64 {
     config: Config {
         kind: Kind::Standard,
@@ -198,6 +218,7 @@ impl Base64 {
             Kind::Standard => STANDARD_CHARSET,
             Kind::UrlSafe => URL_SAFE_CHARSET,
             Kind::Bcrypt => BCRYPT_CHARSET,
+            Kind::Crypt => CRYPT_CHARSET,
         }
     }
 
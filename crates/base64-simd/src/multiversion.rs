@@ -5,7 +5,16 @@ vsimd::dispatch!(
     signature   = {pub(crate) unsafe fn(src: *const u8, len: usize, dst: *mut u8, config: Config) -> ()},
     fallback    = {crate::encode::encode_fallback},
     simd        = {crate::encode::encode_simd},
-    targets     = {"avx2", "ssse3", "neon", "simd128"},
+    targets     = {"avx2", "ssse3", "sse2", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {encode_utf16},
+    signature   = {pub(crate) unsafe fn(src: *const u8, len: usize, dst: *mut u16, config: Config) -> ()},
+    fallback    = {crate::encode::encode_fallback},
+    simd        = {crate::encode::encode_simd},
+    targets     = {"avx2", "ssse3", "sse2", "neon", "simd128"},
     fastest     = {"avx2", "neon", "simd128"},
 );
 
@@ -14,7 +23,7 @@ vsimd::dispatch!(
     signature   = {pub(crate) unsafe fn(src: *const u8, dst: *mut u8, n: usize, config: Config) -> Result<(), Error>},
     fallback    = {crate::decode::decode_fallback},
     simd        = {crate::decode::decode_simd},
-    targets     = {"avx2", "ssse3", "neon", "simd128"},
+    targets     = {"avx2", "ssse3", "sse2", "neon", "simd128"},
     fastest     = {"avx2", "neon", "simd128"},
 );
 
@@ -23,15 +32,42 @@ vsimd::dispatch!(
     signature   = {pub(crate) unsafe fn(src: *const u8, n: usize, config: Config) -> Result<(), Error>},
     fallback    = {crate::check::check_fallback},
     simd        = {crate::check::check_simd},
-    targets     = {"avx2", "ssse3", "neon", "simd128"},
+    targets     = {"avx2", "ssse3", "sse2", "neon", "simd128"},
     fastest     = {"avx2", "neon", "simd128"},
 );
 
 vsimd::dispatch!(
     name        = {find_non_ascii_whitespace},
     signature   = {pub unsafe fn(src: *const u8, len: usize) -> usize},
-    fallback    = {crate::ascii::find_non_ascii_whitespace_fallback},
-    simd        = {crate::ascii::find_non_ascii_whitespace_simd},
+    fallback    = {vsimd::ascii::find_non_ascii_whitespace_fallback},
+    simd        = {vsimd::ascii::find_non_ascii_whitespace_simd},
+    targets     = {"avx2", "sse2", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {narrow_ascii},
+    signature   = {pub(crate) unsafe fn(src: *const u16, len: usize, dst: *mut u8) -> Result<(), Error>},
+    fallback    = {crate::utf16::narrow_ascii_fallback},
+    simd        = {crate::utf16::narrow_ascii_simd},
     targets     = {"avx2", "sse2", "neon", "simd128"},
     fastest     = {"avx2", "neon", "simd128"},
 );
+
+vsimd::dispatch!(
+    name        = {find_alphabet},
+    signature   = {pub(crate) unsafe fn(src: *const u8, len: usize, config: Config) -> usize},
+    fallback    = {crate::spans::find_alphabet_fallback},
+    simd        = {crate::spans::find_alphabet_simd},
+    targets     = {"avx2", "ssse3", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {find_non_alphabet},
+    signature   = {pub(crate) unsafe fn(src: *const u8, len: usize, config: Config) -> usize},
+    fallback    = {crate::spans::find_non_alphabet_fallback},
+    simd        = {crate::spans::find_non_alphabet_simd},
+    targets     = {"avx2", "ssse3", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
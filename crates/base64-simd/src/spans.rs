@@ -0,0 +1,194 @@
+use crate::alsw::{BCRYPT_ALSW_CHECK_X2, CRYPT_ALSW_CHECK_X2, STANDARD_ALSW_CHECK_X2, URL_SAFE_ALSW_CHECK_X2};
+use crate::decode::{BCRYPT_DECODE_TABLE, CRYPT_DECODE_TABLE, STANDARD_DECODE_TABLE, URL_SAFE_DECODE_TABLE};
+use crate::{Base64, Config, Kind};
+
+use vsimd::alsw::AlswLut;
+use vsimd::mask::{u8x32_highbit_all, u8x32_highbit_any};
+use vsimd::tools::slice_parts;
+use vsimd::vector::V256;
+use vsimd::SIMD256;
+
+use core::ops::Range;
+
+#[inline(always)]
+fn decode_table(kind: Kind) -> &'static [u8; 256] {
+    match kind {
+        Kind::Standard => STANDARD_DECODE_TABLE,
+        Kind::UrlSafe => URL_SAFE_DECODE_TABLE,
+        Kind::Bcrypt => BCRYPT_DECODE_TABLE,
+        Kind::Crypt => CRYPT_DECODE_TABLE,
+    }
+}
+
+#[inline(always)]
+fn check_lut(kind: Kind) -> AlswLut<V256> {
+    match kind {
+        Kind::Standard => STANDARD_ALSW_CHECK_X2,
+        Kind::UrlSafe => URL_SAFE_ALSW_CHECK_X2,
+        Kind::Bcrypt => BCRYPT_ALSW_CHECK_X2,
+        Kind::Crypt => CRYPT_ALSW_CHECK_X2,
+    }
+}
+
+#[inline(always)]
+unsafe fn find_short<const IN_CHARSET: bool>(mut src: *const u8, len: usize, table: &[u8; 256]) -> usize {
+    let base = src;
+    let end = base.add(len);
+    while src < end {
+        let is_valid = *table.get_unchecked(src.read() as usize) != 0xff;
+        if is_valid == IN_CHARSET {
+            break;
+        }
+        src = src.add(1);
+    }
+    src.offset_from(base) as usize
+}
+
+#[inline]
+pub(crate) unsafe fn find_non_alphabet_fallback(src: *const u8, len: usize, config: Config) -> usize {
+    find_short::<false>(src, len, decode_table(config.kind))
+}
+
+#[inline(always)]
+pub(crate) unsafe fn find_non_alphabet_simd<S: SIMD256>(s: S, mut src: *const u8, len: usize, config: Config) -> usize {
+    let base = src;
+    let check = check_lut(config.kind);
+
+    let end = src.add(len / 32 * 32);
+    while src < end {
+        let x = s.v256_load_unaligned(src);
+        let c = vsimd::alsw::classify_ascii_xn(s, x, check);
+        if u8x32_highbit_any(s, c) {
+            break;
+        }
+        src = src.add(32);
+    }
+
+    let checked_len = src.offset_from(base) as usize;
+    checked_len + find_non_alphabet_fallback(src, len - checked_len, config)
+}
+
+#[inline]
+pub(crate) unsafe fn find_alphabet_fallback(src: *const u8, len: usize, config: Config) -> usize {
+    find_short::<true>(src, len, decode_table(config.kind))
+}
+
+#[inline(always)]
+pub(crate) unsafe fn find_alphabet_simd<S: SIMD256>(s: S, mut src: *const u8, len: usize, config: Config) -> usize {
+    let base = src;
+    let check = check_lut(config.kind);
+
+    let end = src.add(len / 32 * 32);
+    while src < end {
+        let x = s.v256_load_unaligned(src);
+        let c = vsimd::alsw::classify_ascii_xn(s, x, check);
+        if !u8x32_highbit_all(s, c) {
+            break;
+        }
+        src = src.add(32);
+    }
+
+    let checked_len = src.offset_from(base) as usize;
+    checked_len + find_alphabet_fallback(src, len - checked_len, config)
+}
+
+/// An iterator over the base64 spans embedded in a text.
+///
+/// This struct is created by [`Base64::find_spans`] and [`Base64::find_valid_spans`].
+#[derive(Debug)]
+pub struct Spans<'a> {
+    config: Config,
+    text: &'a [u8],
+    pos: usize,
+    min_len: usize,
+    validate: bool,
+}
+
+impl Spans<'_> {
+    #[inline(always)]
+    fn next_span(&mut self) -> Option<Range<usize>> {
+        let text = self.text;
+        let config = self.config;
+
+        let (src, len) = slice_parts(text);
+        unsafe {
+            let pos = self.pos;
+            let start = pos + crate::multiversion::find_alphabet::auto(src.add(pos), len - pos, config);
+            if start == len {
+                self.pos = len;
+                return None;
+            }
+
+            let mut end = start + crate::multiversion::find_non_alphabet::auto(src.add(start), len - start, config);
+
+            if config.extra.padding() {
+                let mut i = 0;
+                while i < 2 && end < len && *text.get_unchecked(end) == b'=' {
+                    end += 1;
+                    i += 1;
+                }
+            }
+
+            self.pos = end;
+            Some(start..end)
+        }
+    }
+}
+
+impl Iterator for Spans<'_> {
+    type Item = Range<usize>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let span = self.next_span()?;
+            if span.len() < self.min_len {
+                continue;
+            }
+            if self.validate {
+                let base64 = Base64 { config: self.config };
+                if base64.check(&self.text[span.clone()]).is_err() {
+                    continue;
+                }
+            }
+            return Some(span);
+        }
+    }
+}
+
+impl Base64 {
+    /// Finds the base64 spans embedded in `text`.
+    ///
+    /// A span is a maximal run of characters in the charset,
+    /// followed by at most two padding characters if this variant uses padding.
+    /// Spans shorter than `min_len` are skipped.
+    ///
+    /// The spans are not guaranteed to be decodable. See [`Base64::find_valid_spans`].
+    #[inline]
+    #[must_use]
+    pub fn find_spans<'a>(&self, text: &'a [u8], min_len: usize) -> Spans<'a> {
+        Spans {
+            config: self.config,
+            text,
+            pos: 0,
+            min_len,
+            validate: false,
+        }
+    }
+
+    /// Finds the base64 spans embedded in `text` which can be decoded.
+    ///
+    /// This function is the same as [`Base64::find_spans`] except that
+    /// spans failing [`Base64::check`] are skipped.
+    #[inline]
+    #[must_use]
+    pub fn find_valid_spans<'a>(&self, text: &'a [u8], min_len: usize) -> Spans<'a> {
+        Spans {
+            config: self.config,
+            text,
+            pos: 0,
+            min_len,
+            validate: true,
+        }
+    }
+}
@@ -0,0 +1,286 @@
+use crate::Kind;
+use crate::{BCRYPT_CHARSET, CRYPT_CHARSET, STANDARD_CHARSET, URL_SAFE_CHARSET};
+
+use vsimd::isa::SSE2;
+use vsimd::mask::u8x16_highbit_all;
+use vsimd::tools::read;
+use vsimd::vector::V128;
+use vsimd::SIMD128;
+
+// Without `pshufb`, characters are translated by comparisons against the
+// contiguous runs of the charset.
+
+#[derive(Clone, Copy)]
+pub(crate) struct EncodeSteps {
+    base: u8,
+    threshold: [u8; 4],
+    delta: [u8; 4],
+}
+
+const fn encode_steps(charset: &'static [u8; 64]) -> EncodeSteps {
+    let mut steps = EncodeSteps {
+        base: charset[0],
+        threshold: [63; 4],
+        delta: [0; 4],
+    };
+    let mut k = 0;
+    let mut i = 1;
+    while i < 64 {
+        let prev = charset[i - 1].wrapping_sub((i - 1) as u8);
+        let curr = charset[i].wrapping_sub(i as u8);
+        if prev != curr {
+            steps.threshold[k] = (i - 1) as u8;
+            steps.delta[k] = curr.wrapping_sub(prev);
+            k += 1;
+        }
+        i += 1;
+    }
+    steps
+}
+
+#[derive(Clone, Copy)]
+pub(crate) struct DecodeRanges {
+    lo: [u8; 5],
+    len: [u8; 5],
+    offset: [u8; 5],
+}
+
+const fn decode_ranges(charset: &'static [u8; 64]) -> DecodeRanges {
+    let mut ranges = DecodeRanges {
+        lo: [0; 5],
+        len: [0; 5],
+        offset: [0; 5],
+    };
+    let mut k = 0;
+    let mut i = 0;
+    while i < 64 {
+        let start = i;
+        while i + 1 < 64 && charset[i + 1] == charset[i].wrapping_add(1) {
+            i += 1;
+        }
+        i += 1;
+        ranges.lo[k] = charset[start];
+        ranges.len[k] = (i - start) as u8;
+        ranges.offset[k] = (start as u8).wrapping_sub(charset[start]);
+        k += 1;
+    }
+    ranges
+}
+
+const STANDARD_ENCODE_STEPS: EncodeSteps = encode_steps(STANDARD_CHARSET);
+const URL_SAFE_ENCODE_STEPS: EncodeSteps = encode_steps(URL_SAFE_CHARSET);
+const BCRYPT_ENCODE_STEPS: EncodeSteps = encode_steps(BCRYPT_CHARSET);
+const CRYPT_ENCODE_STEPS: EncodeSteps = encode_steps(CRYPT_CHARSET);
+
+const STANDARD_DECODE_RANGES: DecodeRanges = decode_ranges(STANDARD_CHARSET);
+const URL_SAFE_DECODE_RANGES: DecodeRanges = decode_ranges(URL_SAFE_CHARSET);
+const BCRYPT_DECODE_RANGES: DecodeRanges = decode_ranges(BCRYPT_CHARSET);
+const CRYPT_DECODE_RANGES: DecodeRanges = decode_ranges(CRYPT_CHARSET);
+
+#[inline(always)]
+pub(crate) fn encode_steps_of(kind: Kind) -> EncodeSteps {
+    match kind {
+        Kind::Standard => STANDARD_ENCODE_STEPS,
+        Kind::UrlSafe => URL_SAFE_ENCODE_STEPS,
+        Kind::Bcrypt => BCRYPT_ENCODE_STEPS,
+        Kind::Crypt => CRYPT_ENCODE_STEPS,
+    }
+}
+
+#[inline(always)]
+pub(crate) fn decode_ranges_of(kind: Kind) -> DecodeRanges {
+    match kind {
+        Kind::Standard => STANDARD_DECODE_RANGES,
+        Kind::UrlSafe => URL_SAFE_DECODE_RANGES,
+        Kind::Bcrypt => BCRYPT_DECODE_RANGES,
+        Kind::Crypt => CRYPT_DECODE_RANGES,
+    }
+}
+
+/// Loads 12 bytes into four u32 lanes: `{AAA?|BBB?|CCC?|DDD?}`
+///
+/// The source must have at least 13 readable bytes.
+#[inline(always)]
+pub(crate) unsafe fn load_bytes12(s: SSE2, src: *const u8) -> V128 {
+    let lanes = [0, 3, 6, 9].map(|i| src.add(i).cast::<u32>().read_unaligned());
+    s.v128_load_unaligned(lanes.as_ptr().cast())
+}
+
+#[inline(always)]
+pub(crate) fn split_bits(s: SSE2, x: V128) -> V128 {
+    // x: {aaaaaabb|bbbbcccc|ccdddddd|????????} x4
+
+    let x1 = s.v128_and(s.u32x4_shr::<2>(x), s.u32x4_splat(0x0000_003f));
+    let x2 = s.v128_and(s.u32x4_shl::<12>(x), s.u32x4_splat(0x0000_3000));
+    let x3 = s.v128_and(s.u32x4_shr::<4>(x), s.u32x4_splat(0x0000_0f00));
+    let x4 = s.v128_and(s.u32x4_shl::<10>(x), s.u32x4_splat(0x003c_0000));
+    let x5 = s.v128_and(s.u32x4_shr::<6>(x), s.u32x4_splat(0x0003_0000));
+    let x6 = s.v128_and(s.u32x4_shl::<8>(x), s.u32x4_splat(0x3f00_0000));
+
+    s.v128_or(s.v128_or(s.v128_or(x1, x2), s.v128_or(x3, x4)), s.v128_or(x5, x6))
+    // {00aaaaaa|00bbbbbb|00cccccc|00dddddd} x4
+}
+
+#[inline(always)]
+pub(crate) fn split_bits_le(s: SSE2, x: V128) -> V128 {
+    // x: {bbaaaaaa|ccccbbbb|ddddddcc|????????} x4
+
+    let x1 = s.v128_and(x, s.u32x4_splat(0x0000_003f));
+    let x2 = s.v128_and(s.u32x4_shl::<2>(x), s.u32x4_splat(0x0000_3f00));
+    let x3 = s.v128_and(s.u32x4_shl::<4>(x), s.u32x4_splat(0x003f_0000));
+    let x4 = s.v128_and(s.u32x4_shl::<6>(x), s.u32x4_splat(0x3f00_0000));
+
+    s.v128_or(s.v128_or(x1, x2), s.v128_or(x3, x4))
+    // {00aaaaaa|00bbbbbb|00cccccc|00dddddd} x4
+}
+
+#[inline(always)]
+pub(crate) fn encode_values(s: SSE2, x: V128, steps: EncodeSteps) -> V128 {
+    // x: {00aaaaaa|00bbbbbb|00cccccc|00dddddd} x4
+
+    let mut shift = s.u8x16_splat(steps.base);
+    let mut i = 0;
+    while i < 4 {
+        let m = s.i8x16_lt(s.u8x16_splat(steps.threshold[i]), x);
+        shift = s.u8x16_add(shift, s.v128_and(m, s.u8x16_splat(steps.delta[i])));
+        i += 1;
+    }
+    s.u8x16_add(x, shift)
+    // {{ascii}} x16
+}
+
+/// Returns `(values, is_valid)`.
+#[inline(always)]
+pub(crate) fn decode_values(s: SSE2, x: V128, ranges: DecodeRanges) -> (V128, bool) {
+    let mut valid = s.v128_create_zero();
+    let mut offset = s.v128_create_zero();
+    let mut i = 0;
+    while i < 5 {
+        let x1 = s.u8x16_sub(x, s.u8x16_splat(ranges.lo[i].wrapping_add(0x80)));
+        let in_range = s.i8x16_lt(x1, s.u8x16_splat(ranges.len[i].wrapping_add(0x80)));
+        valid = s.v128_or(valid, in_range);
+        offset = s.v128_or(offset, s.v128_and(in_range, s.u8x16_splat(ranges.offset[i])));
+        i += 1;
+    }
+    let values = s.u8x16_add(x, offset);
+    (values, u8x16_highbit_all(s, valid))
+}
+
+#[inline(always)]
+pub(crate) fn merge_bits(s: SSE2, x: V128) -> V128 {
+    // x: {00aaaaaa|00bbbbbb|00cccccc|00dddddd} x4
+
+    let x1 = s.u16x8_shl::<6>(s.v128_and(x, s.u16x8_splat(0x00ff)));
+    let x2 = s.u16x8_shr::<8>(x);
+    let x3 = s.v128_or(x1, x2);
+    // x3: {aabbbbbb|0000aaaa|ccdddddd|0000cccc} x4
+
+    let m = s.u32x4_splat(u32::from_le_bytes([0x00, 0x10, 0x01, 0x00]));
+    s.i16x8_madd(x3, m)
+    // {ccdddddd|bbbbcccc|aaaaaabb|00000000} x4
+}
+
+#[inline(always)]
+pub(crate) fn merge_bits_le(s: SSE2, x: V128) -> V128 {
+    // x: {00aaaaaa|00bbbbbb|00cccccc|00dddddd} x4
+
+    let x1 = s.v128_and(x, s.u16x8_splat(0x00ff));
+    let x2 = s.u16x8_shl::<6>(s.u16x8_shr::<8>(x));
+    let x3 = s.v128_or(x1, x2);
+    // x3: {bbaaaaaa|0000bbbb|ddcccccc|0000dddd} x4
+
+    let m = s.u32x4_splat(u32::from_le_bytes([0x01, 0x00, 0x00, 0x10]));
+    s.i16x8_madd(x3, m)
+    // {bbaaaaaa|ccccbbbb|ddddddcc|00000000} x4
+}
+
+/// Stores the low 3 bytes of each u32 lane, in reversed order if `BE` is true.
+///
+/// The destination must have at least 13 writable bytes.
+#[inline(always)]
+pub(crate) unsafe fn store_bytes12<const BE: bool>(s: SSE2, dst: *mut u8, x: V128) {
+    let mut lanes = [0u32; 4];
+    s.v128_store_unaligned(lanes.as_mut_ptr().cast(), x);
+
+    let mut i = 0;
+    while i < 4 {
+        let mut y = read(lanes.as_ptr(), i);
+        if BE {
+            y = y.swap_bytes() >> 8;
+        }
+        dst.add(i * 3).cast::<u32>().write_unaligned(y.to_le());
+        i += 1;
+    }
+}
+
+#[cfg(all(test, any(target_arch = "x86", target_arch = "x86_64")))]
+mod tests {
+    use crate::decode::decoded_length;
+    use crate::encode::encoded_length_unchecked;
+    use crate::multiversion::{check, decode, encode, encode_utf16};
+    use crate::Base64;
+    use crate::{
+        BCRYPT, BCRYPT_NO_PAD, CRYPT, STANDARD, STANDARD_FORGIVING, STANDARD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD,
+    };
+
+    use rand::RngCore;
+
+    #[test]
+    fn sse2_consistency() {
+        let variants: [&Base64; 8] = [
+            &STANDARD,
+            &URL_SAFE,
+            &BCRYPT,
+            &STANDARD_NO_PAD,
+            &URL_SAFE_NO_PAD,
+            &BCRYPT_NO_PAD,
+            &CRYPT,
+            &STANDARD_FORGIVING,
+        ];
+
+        for n in 0..200 {
+            let mut bytes = vec![0u8; n];
+            rand::thread_rng().fill_bytes(&mut bytes);
+
+            for base64 in variants {
+                let config = base64.config;
+                let m = encoded_length_unchecked(n, config);
+
+                let mut expected = vec![0u8; m];
+                let mut encoded = vec![0u8; m];
+                unsafe {
+                    crate::encode::encode_fallback(bytes.as_ptr(), n, expected.as_mut_ptr(), config);
+                    encode::sse2(bytes.as_ptr(), n, encoded.as_mut_ptr(), config);
+                }
+                assert_eq!(encoded, expected);
+
+                let mut encoded_utf16 = vec![0u16; m];
+                unsafe { encode_utf16::sse2(bytes.as_ptr(), n, encoded_utf16.as_mut_ptr(), config) };
+                assert!(encoded_utf16.iter().copied().eq(expected.iter().map(|&c| u16::from(c))));
+
+                let (k, _) = decoded_length(&encoded, config).unwrap();
+                let mut decoded = vec![0u8; n];
+                unsafe {
+                    decode::sse2(encoded.as_ptr(), decoded.as_mut_ptr(), k, config).unwrap();
+                    check::sse2(encoded.as_ptr(), k, config).unwrap();
+                }
+                assert_eq!(decoded, bytes);
+
+                let mut inplace = encoded.clone();
+                unsafe { decode::sse2(inplace.as_ptr(), inplace.as_mut_ptr(), k, config).unwrap() };
+                assert_eq!(&inplace[..n], bytes);
+
+                for i in 0..k {
+                    for bad in [b'=', b'*', b'\n', 0x80, 0xff] {
+                        let mut invalid = encoded.clone();
+                        invalid[i] = bad;
+                        unsafe {
+                            assert!(decode::sse2(invalid.as_ptr(), decoded.as_mut_ptr(), k, config).is_err());
+                            assert!(check::sse2(invalid.as_ptr(), k, config).is_err());
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
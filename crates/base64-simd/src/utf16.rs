@@ -0,0 +1,149 @@
+use crate::decode::decoded_length;
+use crate::encode::encoded_length_unchecked;
+use crate::{Base64, Error, Out};
+
+use vsimd::isa::{NEON, SSE2, WASM128};
+use vsimd::mask::u8x32_highbit_any;
+use vsimd::matches_isa;
+use vsimd::tools::{read, slice_mut, slice_parts, write};
+use vsimd::vector::V256;
+use vsimd::SIMD256;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[inline]
+pub(crate) unsafe fn narrow_ascii_fallback(src: *const u16, len: usize, dst: *mut u8) -> Result<(), Error> {
+    let mut flag = 0;
+    let mut i = 0;
+    while i < len {
+        let x = read(src, i);
+        flag |= x;
+        write(dst, i, x as u8);
+        i += 1;
+    }
+    ensure!(flag < 0x80);
+    Ok(())
+}
+
+#[inline(always)]
+fn is_ascii_u16x32<S: SIMD256>(s: S, x: (V256, V256)) -> bool {
+    let x = s.v256_or(x.0, x.1);
+    let m = s.u16x16_lt(s.u16x16_splat(0x7f), x);
+    !u8x32_highbit_any(s, m)
+}
+
+#[inline(always)]
+fn narrow_u16x32<S: SIMD256>(s: S, x: (V256, V256)) -> V256 {
+    if matches_isa!(S, SSE2) {
+        let (x0, x1) = x.0.to_v128x2();
+        let (x2, x3) = x.1.to_v128x2();
+        return V256::from_v128x2((s.i16x8_packus(x0, x1), s.i16x8_packus(x2, x3)));
+    }
+    if matches_isa!(S, NEON | WASM128) {
+        return s.u8x32_unzip_even(x.0, x.1);
+    }
+    unreachable!()
+}
+
+#[inline(always)]
+pub(crate) unsafe fn narrow_ascii_simd<S: SIMD256>(
+    s: S,
+    mut src: *const u16,
+    len: usize,
+    mut dst: *mut u8,
+) -> Result<(), Error> {
+    let end = src.add(len / 32 * 32);
+    while src < end {
+        let x0 = s.v256_load_unaligned(src.cast());
+        let x1 = s.v256_load_unaligned(src.add(16).cast());
+        ensure!(is_ascii_u16x32(s, (x0, x1)));
+
+        let y = narrow_u16x32(s, (x0, x1));
+        s.v256_store_unaligned(dst, y);
+
+        src = src.add(32);
+        dst = dst.add(32);
+    }
+
+    narrow_ascii_fallback(src, len % 32, dst)
+}
+
+impl Base64 {
+    /// Decodes a base64 string in UTF-16 to bytes.
+    ///
+    /// The code units are narrowed to bytes chunk by chunk, without allocating a UTF-8 copy of `src`.
+    ///
+    /// # Errors
+    /// This function returns `Err` if
+    /// + the length of `dst` is not enough.
+    /// + the content of `src` is invalid.
+    #[inline]
+    pub fn decode_utf16<'d>(&self, src: &[u16], mut dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Error> {
+        const CHUNK: usize = 1024;
+
+        unsafe {
+            let (mut n, m) = decoded_length(src, self.config)?;
+            ensure!(dst.len() >= m);
+
+            let mut buf = [0u8; CHUNK];
+
+            let mut src = src.as_ptr();
+            let dst = dst.as_mut_ptr();
+            let mut dp = dst;
+
+            loop {
+                let len = n.min(CHUNK);
+                crate::multiversion::narrow_ascii::auto(src, len, buf.as_mut_ptr())?;
+                crate::multiversion::decode::auto(buf.as_ptr(), dp, len, self.config)?;
+
+                if n == len {
+                    break;
+                }
+
+                src = src.add(CHUNK);
+                dp = dp.add(CHUNK / 4 * 3);
+                n -= CHUNK;
+            }
+
+            Ok(slice_mut(dst, m))
+        }
+    }
+
+    /// Encodes bytes to a base64 string in UTF-16.
+    ///
+    /// The encoded ASCII is widened to code units when it is stored.
+    ///
+    /// # Errors
+    /// This function returns `Err` if the length of `dst` is not enough.
+    #[inline]
+    pub fn encode_utf16<'d>(&self, src: &[u8], mut dst: Out<'d, [u16]>) -> Result<&'d mut [u16], Error> {
+        unsafe {
+            let m = encoded_length_unchecked(src.len(), self.config);
+            ensure!(dst.len() >= m);
+
+            let (src, len) = slice_parts(src);
+            let dst = dst.as_mut_ptr();
+            crate::multiversion::encode_utf16::auto(src, len, dst, self.config);
+
+            Ok(slice_mut(dst, m))
+        }
+    }
+
+    /// Encodes bytes to a base64 string in UTF-16 and returns a new [`Vec<u16>`](Vec).
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[cfg(feature = "alloc")]
+    #[inline]
+    #[must_use]
+    pub fn encode_to_utf16_vec(&self, data: impl AsRef<[u8]>) -> Vec<u16> {
+        let data = data.as_ref();
+        let m = self.encoded_length(data.len());
+        let mut vec = Vec::with_capacity(m);
+        unsafe {
+            let (src, len) = slice_parts(data);
+            crate::multiversion::encode_utf16::auto(src, len, vec.as_mut_ptr(), self.config);
+            vec.set_len(m);
+        }
+        vec
+    }
+}
@@ -127,6 +127,30 @@ fn random() {
     }
 }
 
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn bcrypt_check() {
+    use base64_simd::{BCRYPT, BCRYPT_NO_PAD};
+
+    for n in 0..128 {
+        let bytes = rand_bytes(n);
+
+        for base64 in [BCRYPT, BCRYPT_NO_PAD] {
+            let mut buf = vec![0u8; base64.encoded_length(n)];
+            let encoded = base64.encode(&bytes, buf.as_out()).unwrap();
+            assert!(base64.check(encoded).is_ok());
+
+            if n >= 30 {
+                for pos in [0, 20, 37] {
+                    let mut invalid = encoded.to_owned();
+                    invalid[pos] = b'+';
+                    assert!(base64.check(&invalid).is_err());
+                }
+            }
+        }
+    }
+}
+
 /// <https://eprint.iacr.org/2022/361>
 #[cfg_attr(not(target_arch = "wasm32"), test)]
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
@@ -157,6 +181,240 @@ fn canonicity() {
     }
 }
 
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn crypt() {
+    use base64_simd::CRYPT;
+
+    fn crypt_encode(bytes: &[u8]) -> Vec<u8> {
+        const CHARSET: &[u8; 64] = b"./0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+        let mut ans = Vec::new();
+        for chunk in bytes.chunks(3) {
+            let mut w = 0u32;
+            for (i, &b) in chunk.iter().enumerate() {
+                w |= (b as u32) << (i * 8);
+            }
+            for i in 0..=chunk.len() {
+                ans.push(CHARSET[((w >> (i * 6)) & 0x3f) as usize]);
+            }
+        }
+        ans
+    }
+
+    for n in 0..128 {
+        let bytes = rand_bytes(n);
+        let encoded = crypt_encode(&bytes);
+
+        let mut buf = vec![0u8; CRYPT.encoded_length(n)];
+        let ans = CRYPT.encode(&bytes, buf.as_out()).unwrap();
+        assert_eq!(ans, encoded);
+        assert!(CRYPT.check(ans).is_ok());
+
+        let mut buf = encoded.clone();
+        let ans = CRYPT.decode_inplace(&mut buf).unwrap();
+        assert_eq!(ans, bytes);
+
+        let mut buf = vec![0u8; n];
+        let ans = CRYPT.decode(&encoded, buf.as_out()).unwrap();
+        assert_eq!(ans, bytes);
+    }
+
+    assert!(CRYPT.check(b"..").is_ok());
+    assert!(CRYPT.check(b".1").is_ok());
+    assert!(CRYPT.check(b".2").is_err());
+    assert!(CRYPT.check(b"..D").is_ok());
+    assert!(CRYPT.check(b"..E").is_err());
+    assert!(CRYPT.check(b"aGVs+G8").is_err());
+
+    {
+        use const_str::hex;
+
+        let digest = hex!("c34b42ee22c1729bd0032fab3faf0373");
+        let encoded = "z6rkjitG1.hEnBUv/zW6f0";
+        let mut buf = [0u8; 22];
+        let ans = base64_simd::crypt_encode_md5(&digest, buf.as_out());
+        assert_eq!(ans, encoded.as_bytes());
+        let mut buf = [0u8; 16];
+        let ans = base64_simd::crypt_decode_md5(encoded.as_bytes(), buf.as_out()).unwrap();
+        assert_eq!(*ans, digest);
+
+        let digest = hex!("587e545dd6e5c163aa31c5a15a62840ec56ebfb802f00f759daced69954dcb76");
+        let encoded = "0IAKVu5wIxUKp7KL2ORbZne1hLQkiBKOeKtjBVPA9P5";
+        let mut buf = [0u8; 43];
+        let ans = base64_simd::crypt_encode_sha256(&digest, buf.as_out());
+        assert_eq!(ans, encoded.as_bytes());
+        let mut buf = [0u8; 32];
+        let ans = base64_simd::crypt_decode_sha256(encoded.as_bytes(), buf.as_out()).unwrap();
+        assert_eq!(*ans, digest);
+
+        let digest = hex!([
+            "fb4025a6d98e5664e3a4fb41f7e36d0b9aa985b28e4a17fa14878acddcdfb594",
+            "8a6485e4f3b6bb6e3a9a682ab1a2e3d84802dac06fdcdb1695ca7e32ddebcbd4",
+        ]);
+        let encoded = "cdoy.dm3uLGgWGVdNDyV8u6q6pgJY7.rTDiq.L9dvz4Z842rPHqxXPFVYrKZ8Dz0OubhvaeARvKVmiiCOusmI1";
+        let mut buf = [0u8; 86];
+        let ans = base64_simd::crypt_encode_sha512(&digest, buf.as_out());
+        assert_eq!(ans, encoded.as_bytes());
+        let mut buf = [0u8; 64];
+        let ans = base64_simd::crypt_decode_sha512(encoded.as_bytes(), buf.as_out()).unwrap();
+        assert_eq!(*ans, digest);
+
+        let mut buf = [0u8; 16];
+        assert!(base64_simd::crypt_decode_md5(b"z6rkjitG1.hEnBUv/zW6f", buf.as_out()).is_err());
+        assert!(base64_simd::crypt_decode_md5(b"z6rkjitG1.hEnBUv/zW6f=", buf.as_out()).is_err());
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn spans() {
+    use std::ops::Range;
+
+    fn naive_spans(base64: &Base64, text: &[u8], min_len: usize, padding: bool) -> Vec<Range<usize>> {
+        let charset = base64.charset();
+        let mut ans = Vec::new();
+        let mut i = 0;
+        while i < text.len() {
+            if !charset.contains(&text[i]) {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            while i < text.len() && charset.contains(&text[i]) {
+                i += 1;
+            }
+            if padding {
+                let mut j = 0;
+                while j < 2 && i < text.len() && text[i] == b'=' {
+                    i += 1;
+                    j += 1;
+                }
+            }
+            if i - start >= min_len {
+                ans.push(start..i);
+            }
+        }
+        ans
+    }
+
+    let text = b"token=aGVsbG8gd29ybGQ= user:Zm9vYmFy, junk aGVsbG9=. x";
+    let spans: Vec<_> = STANDARD.find_spans(text, 8).collect();
+    let found: Vec<&[u8]> = spans.iter().map(|r| &text[r.clone()]).collect();
+    assert_eq!(found, [&b"aGVsbG8gd29ybGQ="[..], b"Zm9vYmFy", b"aGVsbG9="]);
+
+    let spans: Vec<_> = STANDARD.find_valid_spans(text, 8).collect();
+    let found: Vec<&[u8]> = spans.iter().map(|r| &text[r.clone()]).collect();
+    assert_eq!(found, [&b"aGVsbG8gd29ybGQ="[..], b"Zm9vYmFy"]);
+
+    let cases = [
+        (STANDARD, true),
+        (URL_SAFE, true),
+        (STANDARD_NO_PAD, false),
+        (URL_SAFE_NO_PAD, false),
+        (base64_simd::BCRYPT_NO_PAD, false),
+        (base64_simd::CRYPT, false),
+    ];
+
+    for n in [0, 1, 31, 32, 33, 100, 500] {
+        let mut text = rand_bytes(n);
+        for (i, b) in text.iter_mut().enumerate() {
+            if i % 97 < 60 {
+                *b = b"A0+/_-.=z"[(*b % 9) as usize];
+            }
+        }
+
+        for (base64, padding) in &cases {
+            for min_len in [0, 1, 4, 16] {
+                let expected = naive_spans(base64, &text, min_len, *padding);
+                let ans: Vec<_> = base64.find_spans(&text, min_len).collect();
+                assert_eq!(ans, expected);
+
+                let expected: Vec<_> = expected
+                    .into_iter()
+                    .filter(|r| base64.check(&text[r.clone()]).is_ok())
+                    .collect();
+                let ans: Vec<_> = base64.find_valid_spans(&text, min_len).collect();
+                assert_eq!(ans, expected);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn decode_utf16() {
+    let lens = (0..128).chain([767, 768, 769, 1500, 3000]);
+
+    for n in lens {
+        let bytes = rand_bytes(n);
+
+        for base64 in [STANDARD, URL_SAFE, STANDARD_NO_PAD, URL_SAFE_NO_PAD] {
+            let encoded = base64.encode_to_string(&bytes);
+            let units: Vec<u16> = encoded.encode_utf16().collect();
+
+            let mut buf = vec![0u8; n];
+            let ans = base64.decode_utf16(&units, buf.as_out()).unwrap();
+            assert_eq!(ans, bytes);
+
+            if let Some(last) = units.iter().rposition(|&u| u != u16::from(b'=')) {
+                for i in [0, last / 2, last] {
+                    for bad in [0x80, 0xff, 0x100 | units[i], 0xd83d] {
+                        let mut units = units.clone();
+                        units[i] = bad;
+                        assert!(base64.decode_utf16(&units, buf.as_out()).is_err());
+                    }
+                }
+            }
+        }
+
+        let encoded = STANDARD.encode_to_string(&bytes);
+        let spaced: String = encoded.chars().flat_map(|c| [c, ' ']).collect();
+        let units: Vec<u16> = spaced.encode_utf16().collect();
+        let mut buf = vec![0u8; units.len()];
+        let ans = base64_simd::forgiving_decode_utf16(&units, buf.as_out()).unwrap();
+        assert_eq!(ans, bytes);
+    }
+
+    let mut buf = [0u8; 8];
+    let units: Vec<u16> = "Zm9v\u{e9}".encode_utf16().collect();
+    assert!(base64_simd::forgiving_decode_utf16(&units, buf.as_mut_slice().as_out()).is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn encode_utf16() {
+    let test_config = [
+        STANDARD,
+        URL_SAFE,
+        STANDARD_NO_PAD,
+        URL_SAFE_NO_PAD,
+        base64_simd::BCRYPT,
+        base64_simd::CRYPT,
+    ];
+
+    for n in (0..128).chain([1000, 3001]) {
+        let bytes = rand_bytes(n);
+
+        for base64 in &test_config {
+            let expected: Vec<u16> = base64.encode_to_string(&bytes).encode_utf16().collect();
+
+            let mut buf = vec![0u16; base64.encoded_length(n)];
+            let ans = base64.encode_utf16(&bytes, buf.as_out()).unwrap();
+            assert_eq!(ans, expected);
+
+            let ans = base64.encode_to_utf16_vec(&bytes);
+            assert_eq!(ans, expected);
+
+            if !expected.is_empty() {
+                let mut buf = vec![0u16; expected.len() - 1];
+                assert!(base64.encode_utf16(&bytes, buf.as_out()).is_err());
+            }
+        }
+    }
+}
+
 // RUSTFLAGS=-Zsanitizer=address cargo test -p base64-simd --features=parallel -- --include-ignored parallel_encode
 #[cfg(all(not(miri), feature = "parallel"))]
 #[test]
@@ -0,0 +1,109 @@
+use crate::AsciiCase;
+
+use vsimd::tools::slice_parts;
+
+use core::fmt::{self, Write as _};
+use core::mem::MaybeUninit;
+
+/// The number of bytes encoded on the stack at a time.
+const CHUNK_SIZE: usize = 256;
+
+/// A fmt adapter which formats bytes as a hex string.
+///
+/// See [`display`].
+#[derive(Debug, Clone, Copy)]
+pub struct HexDisplay<'a>(&'a [u8]);
+
+/// Returns a fmt adapter which formats bytes as a hex string without allocation.
+///
+/// The adapter implements [`fmt::LowerHex`], [`fmt::UpperHex`] and [`fmt::Display`],
+/// where [`fmt::Display`] is the same as [`fmt::LowerHex`].
+///
+/// The alternate flag (`#`) adds a `0x` prefix.
+/// Width, fill and alignment are supported. The default alignment is left, as for strings.
+/// The `0` flag pads with zeros after the prefix, as for integers.
+///
+/// # Examples
+///
+/// ```
+/// let data = [0xde, 0xad, 0xbe, 0xef];
+/// let hex = hex_simd::display(&data);
+///
+/// assert_eq!(format!("{hex}"), "deadbeef");
+/// assert_eq!(format!("{hex:X}"), "DEADBEEF");
+/// assert_eq!(format!("{hex:#x}"), "0xdeadbeef");
+/// assert_eq!(format!("{hex:*>12}"), "****deadbeef");
+/// assert_eq!(format!("{hex:#012x}"), "0x00deadbeef");
+/// ```
+#[inline]
+#[must_use]
+pub fn display(data: &[u8]) -> HexDisplay<'_> {
+    HexDisplay(data)
+}
+
+impl HexDisplay<'_> {
+    fn write_hex(&self, f: &mut fmt::Formatter<'_>, case: AsciiCase) -> fmt::Result {
+        let mut buf = MaybeUninit::<[u8; CHUNK_SIZE * 2]>::uninit();
+        for chunk in self.0.chunks(CHUNK_SIZE) {
+            let s = unsafe {
+                let (src, len) = slice_parts(chunk);
+                let dst: *mut u8 = buf.as_mut_ptr().cast();
+                crate::multiversion::encode::auto(src, len, dst, case);
+                core::str::from_utf8_unchecked(core::slice::from_raw_parts(dst, len * 2))
+            };
+            f.write_str(s)?;
+        }
+        Ok(())
+    }
+
+    fn fmt_hex(&self, f: &mut fmt::Formatter<'_>, case: AsciiCase) -> fmt::Result {
+        let prefix = if f.alternate() { "0x" } else { "" };
+        let len = self.0.len().saturating_mul(2).saturating_add(prefix.len());
+        let padding = f.width().map_or(0, |width| width.saturating_sub(len));
+
+        if f.sign_aware_zero_pad() {
+            f.write_str(prefix)?;
+            write_repeated(f, '0', padding)?;
+            return self.write_hex(f, case);
+        }
+
+        let (before, after) = match f.align() {
+            None | Some(fmt::Alignment::Left) => (0, padding),
+            Some(fmt::Alignment::Right) => (padding, 0),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        };
+        let fill = f.fill();
+        write_repeated(f, fill, before)?;
+        f.write_str(prefix)?;
+        self.write_hex(f, case)?;
+        write_repeated(f, fill, after)
+    }
+}
+
+fn write_repeated(f: &mut fmt::Formatter<'_>, c: char, n: usize) -> fmt::Result {
+    for _ in 0..n {
+        f.write_char(c)?;
+    }
+    Ok(())
+}
+
+impl fmt::LowerHex for HexDisplay<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_hex(f, AsciiCase::Lower)
+    }
+}
+
+impl fmt::UpperHex for HexDisplay<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_hex(f, AsciiCase::Upper)
+    }
+}
+
+impl fmt::Display for HexDisplay<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <Self as fmt::LowerHex>::fmt(self, f)
+    }
+}
@@ -0,0 +1,369 @@
+//! Hex dumps in the formats of `hexdump -C` and `xxd`.
+//!
+//! # Examples
+//!
+//! ```
+//! use hex_simd::dump::{dump_to_string, undump, DumpFormat};
+//!
+//! let data = b"Hello world!\n";
+//!
+//! let text = dump_to_string(data, DumpFormat::HEXDUMP);
+//! assert_eq!(
+//!     text,
+//!     "00000000  48 65 6c 6c 6f 20 77 6f  72 6c 64 21 0a           |Hello world!.|\n\
+//!      0000000d\n"
+//! );
+//! assert_eq!(undump(text.as_bytes()).unwrap(), data);
+//!
+//! let text = dump_to_string(data, DumpFormat::XXD);
+//! assert_eq!(text, "00000000: 4865 6c6c 6f20 776f 726c 6421 0a         Hello world!.\n");
+//! assert_eq!(undump(text.as_bytes()).unwrap(), data);
+//! ```
+
+use crate::error::offset_in;
+use crate::{AsciiCase, Error, ErrorKind};
+
+use vsimd::tools::slice_parts;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// The layout of a hex dump.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DumpStyle {
+    /// The canonical format of `hexdump -C`.
+    ///
+    /// Bytes are separated by spaces and groups are separated by an extra space.
+    /// The ASCII gutter is enclosed in `|`. A final line shows the total length.
+    Hexdump,
+    /// The default format of `xxd`.
+    ///
+    /// Bytes in a group are written together and groups are separated by a space.
+    Xxd,
+}
+
+/// The format of a hex dump.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DumpFormat {
+    style: DumpStyle,
+    width: usize,
+    group: usize,
+    case: AsciiCase,
+    squeeze: bool,
+}
+
+impl DumpFormat {
+    /// `hexdump -C`: 16 bytes per line in groups of 8, with repeated lines squeezed to `*`.
+    pub const HEXDUMP: Self = Self {
+        style: DumpStyle::Hexdump,
+        width: 16,
+        group: 8,
+        case: AsciiCase::Lower,
+        squeeze: true,
+    };
+
+    /// `xxd`: 16 bytes per line in groups of 2.
+    pub const XXD: Self = Self {
+        style: DumpStyle::Xxd,
+        width: 16,
+        group: 2,
+        case: AsciiCase::Lower,
+        squeeze: false,
+    };
+
+    /// Sets the number of bytes per line.
+    ///
+    /// # Panics
+    /// This function panics if `width` is zero.
+    #[inline]
+    #[must_use]
+    pub const fn width(mut self, width: usize) -> Self {
+        assert!(width > 0);
+        self.width = width;
+        self
+    }
+
+    /// Sets the number of bytes per group.
+    ///
+    /// # Panics
+    /// This function panics if `group` is zero.
+    #[inline]
+    #[must_use]
+    pub const fn group(mut self, group: usize) -> Self {
+        assert!(group > 0);
+        self.group = group;
+        self
+    }
+
+    /// Sets the ascii case of hex characters.
+    #[inline]
+    #[must_use]
+    pub const fn case(mut self, case: AsciiCase) -> Self {
+        self.case = case;
+        self
+    }
+
+    /// Sets whether consecutive identical lines are replaced by a single `*` line.
+    #[inline]
+    #[must_use]
+    pub const fn squeeze(mut self, squeeze: bool) -> Self {
+        self.squeeze = squeeze;
+        self
+    }
+
+    /// Returns the layout of this format.
+    #[inline]
+    #[must_use]
+    pub const fn style(&self) -> DumpStyle {
+        self.style
+    }
+
+    /// Returns the width of the hex column, including the trailing spaces.
+    const fn hex_column_width(&self) -> usize {
+        let groups = (self.width + self.group - 1) / self.group;
+        match self.style {
+            DumpStyle::Hexdump => self.width * 3 + groups,
+            DumpStyle::Xxd => self.width * 2 + groups,
+        }
+    }
+}
+
+#[inline(always)]
+fn gutter_char(x: u8) -> u8 {
+    if (0x20..0x7f).contains(&x) {
+        x
+    } else {
+        b'.'
+    }
+}
+
+fn write_line(line: &mut Vec<u8>, hex: &mut Vec<u8>, offset: usize, data: &[u8], format: &DumpFormat) {
+    use core::fmt::Write as _;
+
+    line.clear();
+    let spaced = match format.style {
+        DumpStyle::Hexdump => {
+            let _ = write!(VecWriter(line), "{offset:08x}  ");
+            true
+        }
+        DumpStyle::Xxd => {
+            let _ = write!(VecWriter(line), "{offset:08x}: ");
+            format.group == 1
+        }
+    };
+
+    // encode the whole line at once, then copy the groups
+    let step = if spaced { 3 } else { 2 };
+    hex.clear();
+    hex.reserve(data.len() * 3);
+    unsafe {
+        let (src, len) = slice_parts(data);
+        let dst = hex.as_mut_ptr();
+        if spaced {
+            crate::multiversion::encode_separated::auto(src, len, dst, b' ', format.case);
+        } else {
+            crate::multiversion::encode::auto(src, len, dst, format.case);
+        }
+        hex.set_len(len * step - usize::from(spaced));
+    }
+
+    let hex_start = line.len();
+    for (i, chunk) in data.chunks(format.group).enumerate() {
+        let start = i * format.group * step;
+        let end = start + chunk.len() * step - usize::from(spaced);
+        line.extend_from_slice(&hex[start..end]);
+        line.push(b' ');
+        if format.style == DumpStyle::Hexdump {
+            line.push(b' ');
+        }
+    }
+    line.resize(hex_start + format.hex_column_width(), b' ');
+
+    match format.style {
+        DumpStyle::Hexdump => {
+            line.push(b'|');
+            line.extend(data.iter().copied().map(gutter_char));
+            line.push(b'|');
+        }
+        DumpStyle::Xxd => {
+            line.push(b' ');
+            line.extend(data.iter().copied().map(gutter_char));
+        }
+    }
+    line.push(b'\n');
+}
+
+struct VecWriter<'a>(&'a mut Vec<u8>);
+
+impl fmt::Write for VecWriter<'_> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.extend_from_slice(s.as_bytes());
+        Ok(())
+    }
+}
+
+/// Writes a hex dump of `data` to `f`.
+///
+/// # Errors
+/// This function returns `Err` if `f` fails to write.
+#[inline]
+pub fn dump_to<W: fmt::Write>(data: &[u8], format: DumpFormat, f: &mut W) -> fmt::Result {
+    let mut line = Vec::new();
+    let mut hex = Vec::new();
+    let mut prev: Option<&[u8]> = None;
+    let mut squeezing = false;
+
+    for (i, chunk) in data.chunks(format.width).enumerate() {
+        if format.squeeze && chunk.len() == format.width && prev == Some(chunk) {
+            if !squeezing {
+                f.write_str("*\n")?;
+                squeezing = true;
+            }
+            continue;
+        }
+        squeezing = false;
+        prev = Some(chunk);
+
+        write_line(&mut line, &mut hex, i * format.width, chunk, &format);
+        f.write_str(unsafe { core::str::from_utf8_unchecked(&line) })?;
+    }
+
+    if format.style == DumpStyle::Hexdump && !data.is_empty() {
+        writeln!(f, "{:08x}", data.len())?;
+    }
+    Ok(())
+}
+
+/// Returns a hex dump of `data`.
+#[inline]
+#[must_use]
+pub fn dump_to_string(data: &[u8], format: DumpFormat) -> String {
+    let mut ans = String::new();
+    let _ = dump_to(data, format, &mut ans);
+    ans
+}
+
+#[inline(always)]
+fn is_hex_digit(x: u8) -> bool {
+    vsimd::hex::unhex(x) != 0xff
+}
+
+/// Parses the offset column. The offset of an invalid character is relative to `line`.
+fn parse_offset(line: &[u8]) -> Result<(usize, &[u8], bool), Error> {
+    let n = line.iter().position(|&x| !is_hex_digit(x)).unwrap_or(line.len());
+    let invalid = || {
+        let byte = line[n];
+        Err(Error::from_kind(ErrorKind::InvalidCharacter { offset: n, byte }))
+    };
+    if n == 0 {
+        return invalid();
+    }
+
+    let mut offset: usize = 0;
+    for &x in &line[..n] {
+        ensure!(offset <= usize::MAX >> 4);
+        offset = offset << 4 | usize::from(vsimd::hex::unhex(x));
+    }
+
+    let rest = &line[n..];
+    match rest.first() {
+        Some(b':') => Ok((offset, &rest[1..], true)),
+        Some(b' ') | None => Ok((offset, rest, false)),
+        Some(_) => invalid(),
+    }
+}
+
+/// Returns the hex column of a line without its offset.
+fn hex_column(rest: &[u8], xxd: bool) -> &[u8] {
+    let end = if xxd {
+        // the gutter follows two spaces
+        let mut i = 0;
+        while i < rest.len() && rest[i] == b' ' {
+            i += 1;
+        }
+        rest[i..].windows(2).position(|w| w == b"  ").map(|p| i + p)
+    } else {
+        rest.iter().position(|&x| x == b'|')
+    };
+    &rest[..end.unwrap_or(rest.len())]
+}
+
+/// The largest gap that [`undump`] fills with zeros.
+const MAX_ZERO_GAP: usize = 1 << 20;
+
+/// Reconstructs bytes from a hex dump, like `xxd -r`.
+///
+/// Both [`DumpStyle::Hexdump`] and [`DumpStyle::Xxd`] are accepted, in either ascii case
+/// and with any width or group size. Each line is placed at its offset:
+/// gaps are filled with zeros, or by repeating the previous line after a `*` line.
+///
+/// # Errors
+/// This function returns `Err` if
+/// + any line is malformed.
+/// + the offset of a line overflows or can not be allocated, which is reported as
+///   [`ErrorKind::InvalidRecord`] at the line.
+/// + a line leaves a gap of more than 1 MiB to be filled with zeros, which is reported as
+///   [`ErrorKind::InvalidRecord`] at the line. Gaps after a `*` line are not limited.
+///
+/// The offset of an invalid character is relative to `text`.
+#[inline]
+pub fn undump(text: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut ans: Vec<u8> = Vec::new();
+    let mut prev = 0..0;
+    let mut squeezed = false;
+    let mut line_buf = Vec::new();
+
+    for line in text.split(|&x| x == b'\n') {
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        if line.iter().all(|&x| x == b' ') {
+            continue;
+        }
+        if line == b"*" {
+            squeezed = true;
+            continue;
+        }
+
+        let (offset, rest, xxd) = parse_offset(line).map_err(|e| e.add_offset(offset_in(text, line)))?;
+
+        line_buf.clear();
+        for token in hex_column(rest, xxd).split(|&x| x == b' ') {
+            if !token.is_empty() {
+                crate::decode_append(token, &mut line_buf).map_err(|e| e.add_offset(offset_in(text, token)))?;
+            }
+        }
+
+        let invalid_record = || {
+            Error::from_kind(ErrorKind::InvalidRecord {
+                offset: offset_in(text, line),
+            })
+        };
+        let end = offset.checked_add(line_buf.len()).ok_or_else(invalid_record)?;
+        if !squeezed && offset > ans.len() && offset - ans.len() > MAX_ZERO_GAP {
+            return Err(invalid_record());
+        }
+        if ans.len() < end {
+            ans.try_reserve(end - ans.len()).map_err(|_| invalid_record())?;
+        }
+
+        if squeezed {
+            ensure!(offset >= ans.len() && !prev.is_empty());
+            while ans.len() < offset {
+                let n = (offset - ans.len()).min(prev.len());
+                ans.extend_from_within(prev.start..prev.start + n);
+            }
+            squeezed = false;
+        }
+
+        if ans.len() < end {
+            ans.resize(end, 0);
+        }
+        ans[offset..end].copy_from_slice(&line_buf);
+        if !line_buf.is_empty() {
+            prev = offset..end;
+        }
+    }
+
+    Ok(ans)
+}
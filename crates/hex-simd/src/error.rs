@@ -1,26 +1,136 @@
 use core::fmt;
 
 /// Hex Error
-pub struct Error(());
+pub struct Error {
+    kind: ErrorKind,
+}
+
+/// The kind of a hex error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// An invalid character is found in the input.
+    InvalidCharacter {
+        /// The offset of the character in the input.
+        offset: usize,
+        /// The value of the character.
+        byte: u8,
+    },
+    /// The length of the input is invalid.
+    InvalidLength,
+    /// The output buffer is too small.
+    BufferTooSmall,
+    /// The checksum of a record does not match its content.
+    InvalidChecksum {
+        /// The offset of the record in the input.
+        offset: usize,
+    },
+    /// A record has an unknown type or a malformed field.
+    InvalidRecord {
+        /// The offset of the record in the input.
+        offset: usize,
+    },
+    /// The input is invalid but the position of the error is unknown.
+    ///
+    /// For example, in-place decoding may have overwritten the invalid part of the input.
+    Invalid,
+}
 
 impl Error {
     #[inline(always)]
     pub(crate) const fn new() -> Self {
-        Error(())
+        Self::from_kind(ErrorKind::Invalid)
+    }
+
+    #[inline(always)]
+    pub(crate) const fn from_kind(kind: ErrorKind) -> Self {
+        Error { kind }
+    }
+
+    /// Moves the offset of the error by `base`.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    pub(crate) const fn add_offset(self, base: usize) -> Self {
+        match self.kind {
+            ErrorKind::InvalidCharacter { offset, byte } => Self::from_kind(ErrorKind::InvalidCharacter {
+                offset: base + offset,
+                byte,
+            }),
+            ErrorKind::InvalidChecksum { offset } => {
+                Self::from_kind(ErrorKind::InvalidChecksum { offset: base + offset })
+            }
+            ErrorKind::InvalidRecord { offset } => Self::from_kind(ErrorKind::InvalidRecord { offset: base + offset }),
+            _ => self,
+        }
+    }
+
+    /// Returns the kind of this error.
+    #[inline]
+    #[must_use]
+    pub const fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Returns the offset of the error in the input, if known.
+    #[inline]
+    #[must_use]
+    pub const fn offset(&self) -> Option<usize> {
+        match self.kind {
+            ErrorKind::InvalidCharacter { offset, .. }
+            | ErrorKind::InvalidChecksum { offset }
+            | ErrorKind::InvalidRecord { offset } => Some(offset),
+            _ => None,
+        }
     }
 }
 
+/// Finds the first character rejected by `is_valid`.
+///
+/// The fast paths only report that the input is invalid.
+/// This function is called after they fail so that the happy path does not pay for the position.
+#[cold]
+#[inline(never)]
+pub(crate) fn locate(src: &[u8], is_valid: impl Fn(usize, u8) -> bool) -> Error {
+    match src.iter().enumerate().find(|&(i, &x)| !is_valid(i, x)) {
+        Some((offset, &byte)) => Error::from_kind(ErrorKind::InvalidCharacter { offset, byte }),
+        None => Error::new(),
+    }
+}
+
+/// Finds the first non-hex character.
+#[cold]
+#[inline(never)]
+pub(crate) fn locate_hex(src: &[u8]) -> Error {
+    locate(src, |_, x| vsimd::hex::unhex(x) != 0xff)
+}
+
+/// Returns the offset of `part` in `text`, where `part` is a subslice of `text`.
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub(crate) fn offset_in(text: &[u8], part: &[u8]) -> usize {
+    part.as_ptr() as usize - text.as_ptr() as usize
+}
+
 impl fmt::Debug for Error {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        <str as fmt::Debug>::fmt("HexError", f)
+        f.debug_tuple("HexError").field(&self.kind).finish()
     }
 }
 
 impl fmt::Display for Error {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        <str as fmt::Display>::fmt("HexError", f)
+        match self.kind {
+            ErrorKind::InvalidCharacter { offset, byte } => {
+                write!(f, "HexError: invalid character {byte:#04x} at offset {offset}")
+            }
+            ErrorKind::InvalidLength => f.write_str("HexError: invalid length"),
+            ErrorKind::BufferTooSmall => f.write_str("HexError: buffer too small"),
+            ErrorKind::InvalidChecksum { offset } => write!(f, "HexError: invalid checksum at offset {offset}"),
+            ErrorKind::InvalidRecord { offset } => write!(f, "HexError: invalid record at offset {offset}"),
+            ErrorKind::Invalid => f.write_str("HexError"),
+        }
     }
 }
 
@@ -34,6 +144,11 @@ macro_rules! ensure {
             return Err($crate::error::Error::new());
         }
     };
+    ($cond:expr, $kind:ident) => {
+        if !$cond {
+            return Err($crate::error::Error::from_kind($crate::error::ErrorKind::$kind));
+        }
+    };
 }
 
 #[allow(unused_macros)]
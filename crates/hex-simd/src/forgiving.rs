@@ -0,0 +1,123 @@
+use crate::error::{self, locate_hex};
+use crate::{Error, Out};
+
+use vsimd::ascii::remove_ascii_whitespace_fallback;
+use vsimd::hex::unhex;
+use vsimd::tools::{slice_mut, slice_parts};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[inline(always)]
+fn find_non_ascii_whitespace(data: &[u8]) -> usize {
+    let (src, len) = slice_parts(data);
+    unsafe { crate::multiversion::find_non_ascii_whitespace::auto(src, len) }
+}
+
+#[inline(always)]
+fn remove_ascii_whitespace_inplace(data: &mut [u8]) -> &mut [u8] {
+    let pos = find_non_ascii_whitespace(data);
+    vsimd::ascii::remove_ascii_whitespace_inplace(data, pos)
+}
+
+/// The number of characters compacted on the stack before each decoding.
+const CHUNK: usize = 256;
+
+/// Finds the first character which is neither a hex character nor an ascii whitespace.
+#[cold]
+#[inline(never)]
+fn locate(src: &[u8]) -> Error {
+    error::locate(src, |_, x| x.is_ascii_whitespace() || unhex(x) != 0xff)
+}
+
+/// Forgiving decodes a hex string to bytes case-insensitively and writes inplace.
+///
+/// ASCII whitespaces are removed before decoding.
+///
+/// The position of an invalid character is not reported
+/// because the input is compacted and overwritten inplace.
+///
+/// # Errors
+/// This function returns `Err` if
+/// + the number of hex characters is odd.
+/// + the content of `data` is invalid.
+#[inline]
+pub fn forgiving_decode_inplace(data: &mut [u8]) -> Result<&mut [u8], Error> {
+    let data = remove_ascii_whitespace_inplace(data);
+    crate::decode_inplace(data)
+}
+
+/// Forgiving decodes a hex string to bytes case-insensitively.
+///
+/// ASCII whitespaces are removed before decoding,
+/// so that hex dumps such as `"de ad\nbe ef"` are accepted.
+///
+/// # Examples
+///
+/// ```
+/// use hex_simd::AsOut;
+///
+/// let mut buf = [0u8; 4];
+/// let ans = hex_simd::forgiving_decode(b"de ad\nBE EF\n", buf.as_mut_slice().as_out());
+/// assert_eq!(ans.unwrap(), [0xde, 0xad, 0xbe, 0xef]);
+/// ```
+///
+/// # Errors
+/// This function returns `Err` if
+/// + the number of hex characters is odd.
+/// + the length of `dst` is not enough.
+/// + the content of `src` is invalid.
+#[inline]
+pub fn forgiving_decode<'d>(src: &[u8], mut dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Error> {
+    let pos = find_non_ascii_whitespace(src);
+    debug_assert!(pos <= src.len());
+
+    if pos == src.len() {
+        return crate::decode(src, dst);
+    }
+
+    let head = pos / 2 * 2;
+    let cap = dst.len();
+    ensure!(cap >= head / 2, BufferTooSmall);
+
+    unsafe {
+        let dst = dst.as_mut_ptr();
+        crate::multiversion::decode::auto(src.as_ptr(), head, dst).map_err(|_| locate_hex(src))?;
+        let mut len = head / 2;
+
+        // The compacted characters are decoded in blocks.
+        // An unpaired character is carried to the next block.
+        let mut buf = [0u8; CHUNK + 1];
+        let mut carry = 0;
+        for chunk in src[head..].chunks(CHUNK) {
+            let n = carry + remove_ascii_whitespace_fallback(chunk.as_ptr(), chunk.len(), buf.as_mut_ptr().add(carry));
+            let m = n / 2 * 2;
+            ensure!(cap - len >= m / 2, BufferTooSmall);
+            crate::multiversion::decode::auto(buf.as_ptr(), m, dst.add(len)).map_err(|_| locate(src))?;
+            len += m / 2;
+            carry = n - m;
+            buf[0] = buf[m];
+        }
+        ensure!(carry == 0, InvalidLength);
+
+        Ok(slice_mut(dst, len))
+    }
+}
+
+/// Forgiving decodes a hex string to bytes case-insensitively and returns a new [`Vec<u8>`](Vec).
+///
+/// ASCII whitespaces are removed before decoding.
+///
+/// # Errors
+/// This function returns `Err` if
+/// + the number of hex characters is odd.
+/// + the content of `data` is invalid.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn forgiving_decode_to_vec(data: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut vec = Vec::with_capacity(data.len() / 2);
+    let len = forgiving_decode(data, Out::from_uninit_slice(vec.spare_capacity_mut()))?.len();
+    unsafe { vec.set_len(len) };
+    Ok(vec)
+}
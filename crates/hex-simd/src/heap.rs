@@ -1,3 +1,4 @@
+use crate::error::locate_hex;
 use crate::{AppendHexDecode, AppendHexEncode, AsciiCase, Error, FromHexDecode, FromHexEncode};
 
 use vsimd::tools::{alloc_uninit_bytes, assume_init, boxed_str, slice_parts};
@@ -12,15 +13,14 @@ fn decode_to_boxed_bytes(src: &[u8]) -> Result<Box<[u8]>, Error> {
         return Ok(Box::from([]));
     }
 
-    ensure!(src.len() % 2 == 0);
+    ensure!(src.len() % 2 == 0, InvalidLength);
 
     unsafe {
         let mut buf = alloc_uninit_bytes(src.len() / 2);
 
         {
-            let (src, len) = slice_parts(src);
             let dst: *mut u8 = buf.as_mut_ptr().cast();
-            crate::multiversion::decode::auto(src, len, dst)?;
+            crate::multiversion::decode::auto(src.as_ptr(), src.len(), dst).map_err(|_| locate_hex(src))?;
         }
 
         Ok(assume_init(buf))
@@ -33,16 +33,15 @@ fn decode_append_vec(src: &[u8], buf: &mut Vec<u8>) -> Result<(), Error> {
         return Ok(());
     }
 
-    ensure!(src.len() % 2 == 0);
+    ensure!(src.len() % 2 == 0, InvalidLength);
     let m = src.len() / 2;
 
     buf.reserve_exact(m);
     let prev_len = buf.len();
 
     unsafe {
-        let (src, len) = slice_parts(src);
         let dst = buf.as_mut_ptr().add(prev_len);
-        crate::multiversion::decode::auto(src, len, dst)?;
+        crate::multiversion::decode::auto(src.as_ptr(), src.len(), dst).map_err(|_| locate_hex(src))?;
 
         buf.set_len(prev_len + m);
         Ok(())
@@ -0,0 +1,234 @@
+//! The Intel HEX file format.
+//!
+//! Each record is a line of `:LLAAAATT<data>CC`, where `LL` is the byte count,
+//! `AAAA` is the address, `TT` is the record type and `CC` is the checksum.
+//!
+//! # Examples
+//!
+//! ```
+//! use hex_simd::ihex::{self, IntelHex, Segment};
+//!
+//! let text = "\
+//!     :020000040001F9\n\
+//!     :02100000AABB89\n\
+//!     :00000001FF\n";
+//!
+//! let file = ihex::parse(text.as_bytes()).unwrap();
+//! assert_eq!(file.segments, [Segment { address: 0x0001_1000, data: vec![0xaa, 0xbb] }]);
+//!
+//! let file = IntelHex {
+//!     segments: vec![Segment { address: 0x0001_fffe, data: vec![1, 2, 3, 4] }],
+//!     ..IntelHex::default()
+//! };
+//! assert_eq!(
+//!     ihex::write_to_string(&file, 16),
+//!     ":020000040001F9\n\
+//!      :02FFFE000102FE\n\
+//!      :020000040002F8\n\
+//!      :020000000304F7\n\
+//!      :00000001FF\n"
+//! );
+//! ```
+
+use crate::error::offset_in;
+use crate::{AsciiCase, Error, ErrorKind};
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// A contiguous run of data at an absolute address.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Segment {
+    /// The address of the first byte.
+    pub address: u32,
+    /// The data bytes.
+    pub data: Vec<u8>,
+}
+
+/// The content of an Intel HEX file.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct IntelHex {
+    /// The data segments in the order of the file.
+    ///
+    /// Consecutive data records at adjacent addresses are merged into one segment.
+    pub segments: Vec<Segment>,
+    /// The `CS:IP` value of a start segment address record (type `03`), as `CS << 16 | IP`.
+    pub start_segment_address: Option<u32>,
+    /// The `EIP` value of a start linear address record (type `05`).
+    pub start_linear_address: Option<u32>,
+}
+
+/// Appends data to the last segment if they are adjacent, or starts a new segment.
+pub(crate) fn push_data(segments: &mut Vec<Segment>, address: u32, data: &[u8]) {
+    match segments.last_mut() {
+        Some(seg) if seg.address.wrapping_add(seg.data.len() as u32) == address => {
+            seg.data.extend_from_slice(data);
+        }
+        _ if data.is_empty() => {}
+        _ => segments.push(Segment {
+            address,
+            data: data.to_vec(),
+        }),
+    }
+}
+
+const DATA: u8 = 0x00;
+const END_OF_FILE: u8 = 0x01;
+const EXTENDED_SEGMENT_ADDRESS: u8 = 0x02;
+const START_SEGMENT_ADDRESS: u8 = 0x03;
+const EXTENDED_LINEAR_ADDRESS: u8 = 0x04;
+const START_LINEAR_ADDRESS: u8 = 0x05;
+
+#[inline(always)]
+fn checksum(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0u8, |acc, &x| acc.wrapping_add(x)).wrapping_neg()
+}
+
+/// Decodes the bytes of a record line and checks its length and checksum.
+/// The offsets of errors are relative to `line`.
+fn parse_record<'b>(line: &[u8], buf: &'b mut Vec<u8>) -> Result<&'b [u8], Error> {
+    match line.first() {
+        Some(b':') => {}
+        Some(&byte) => return Err(Error::from_kind(ErrorKind::InvalidCharacter { offset: 0, byte })),
+        None => return Err(Error::from_kind(ErrorKind::InvalidLength)),
+    }
+
+    buf.clear();
+    crate::decode_append(&line[1..], buf).map_err(|e| e.add_offset(1))?;
+    ensure!(buf.len() >= 5 && buf.len() == usize::from(buf[0]) + 5, InvalidLength);
+
+    if checksum(buf) != 0 {
+        return Err(Error::from_kind(ErrorKind::InvalidChecksum { offset: 0 }));
+    }
+    Ok(buf)
+}
+
+/// Parses an Intel HEX file.
+///
+/// Empty lines are ignored and `\r\n` line endings are accepted.
+/// Records after the end of file record are ignored.
+/// The record payloads are decoded case-insensitively.
+///
+/// # Errors
+/// This function returns `Err` if any record is malformed or has a wrong checksum.
+/// The offset of the error is relative to `text`.
+#[inline]
+pub fn parse(text: &[u8]) -> Result<IntelHex, Error> {
+    let mut ans = IntelHex::default();
+    let mut base: u32 = 0;
+    let mut buf = Vec::new();
+
+    for line in text.split(|&x| x == b'\n') {
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        if line.is_empty() {
+            continue;
+        }
+
+        let line_offset = offset_in(text, line);
+        let record = parse_record(line, &mut buf).map_err(|e| e.add_offset(line_offset))?;
+        let invalid_record = || Error::from_kind(ErrorKind::InvalidRecord { offset: line_offset });
+
+        let address = u16::from_be_bytes([record[1], record[2]]);
+        let data = &record[4..record.len() - 1];
+        let word = |n: usize| match *data {
+            [a, b] if n == 2 => Ok(u32::from(u16::from_be_bytes([a, b]))),
+            [a, b, c, d] if n == 4 => Ok(u32::from_be_bytes([a, b, c, d])),
+            _ => Err(invalid_record()),
+        };
+
+        match record[3] {
+            DATA => {
+                push_data(&mut ans.segments, base.wrapping_add(u32::from(address)), data);
+            }
+            END_OF_FILE => {
+                ensure!(data.is_empty(), InvalidLength);
+                break;
+            }
+            EXTENDED_SEGMENT_ADDRESS => base = word(2)? << 4,
+            START_SEGMENT_ADDRESS => ans.start_segment_address = Some(word(4)?),
+            EXTENDED_LINEAR_ADDRESS => base = word(2)? << 16,
+            START_LINEAR_ADDRESS => ans.start_linear_address = Some(word(4)?),
+            _ => return Err(invalid_record()),
+        }
+    }
+
+    Ok(ans)
+}
+
+fn write_record<W: fmt::Write>(f: &mut W, line: &mut Vec<u8>, address: u16, ty: u8, data: &[u8]) -> fmt::Result {
+    let mut bytes = [0u8; 4 + 255 + 1];
+    let n = data.len();
+    bytes[0] = n as u8;
+    bytes[1..3].copy_from_slice(&address.to_be_bytes());
+    bytes[3] = ty;
+    bytes[4..4 + n].copy_from_slice(data);
+    bytes[4 + n] = checksum(&bytes[..4 + n]);
+
+    line.clear();
+    line.push(b':');
+    crate::encode_append(&bytes[..n + 5], line, AsciiCase::Upper);
+    line.push(b'\n');
+    f.write_str(unsafe { core::str::from_utf8_unchecked(line) })
+}
+
+/// Writes an Intel HEX file to `f`.
+///
+/// Data records contain at most `record_len` bytes and never cross a 64 KiB boundary.
+/// Addresses above 64 KiB are written with extended linear address records.
+/// The hex characters are uppercase and each record ends with `\n`.
+///
+/// # Errors
+/// This function returns `Err` if `f` fails to write.
+///
+/// # Panics
+/// This function panics if `record_len` is zero.
+#[inline]
+pub fn write_to<W: fmt::Write>(file: &IntelHex, record_len: u8, f: &mut W) -> fmt::Result {
+    assert!(record_len > 0);
+
+    let mut line = Vec::new();
+    let mut upper: u16 = 0;
+
+    for seg in &file.segments {
+        let mut address = seg.address;
+        let mut data = seg.data.as_slice();
+        while !data.is_empty() {
+            let hi = (address >> 16) as u16;
+            let lo = address as u16;
+            if hi != upper {
+                write_record(f, &mut line, 0, EXTENDED_LINEAR_ADDRESS, &hi.to_be_bytes())?;
+                upper = hi;
+            }
+
+            let until_boundary = 0x1_0000 - usize::from(lo);
+            let n = data.len().min(usize::from(record_len)).min(until_boundary);
+            write_record(f, &mut line, lo, DATA, &data[..n])?;
+
+            address = address.wrapping_add(n as u32);
+            data = &data[n..];
+        }
+    }
+
+    if let Some(x) = file.start_segment_address {
+        write_record(f, &mut line, 0, START_SEGMENT_ADDRESS, &x.to_be_bytes())?;
+    }
+    if let Some(x) = file.start_linear_address {
+        write_record(f, &mut line, 0, START_LINEAR_ADDRESS, &x.to_be_bytes())?;
+    }
+    write_record(f, &mut line, 0, END_OF_FILE, &[])
+}
+
+/// Returns an Intel HEX file.
+///
+/// See [`write_to`] for the format.
+///
+/// # Panics
+/// This function panics if `record_len` is zero.
+#[inline]
+#[must_use]
+pub fn write_to_string(file: &IntelHex, record_len: u8) -> String {
+    let mut ans = String::new();
+    let _ = write_to(file, record_len, &mut ans);
+    ans
+}
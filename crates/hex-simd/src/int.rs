@@ -0,0 +1,155 @@
+//! Hexadecimal integer parsing and formatting.
+//!
+//! Digits are big-endian, the most significant first, as in `{:x}`.
+//!
+//! # Examples
+//!
+//! ```
+//! use hex_simd::int::{format_u64, format_u64_trimmed, parse_u32, parse_u64};
+//! use hex_simd::{AsOut, AsciiCase};
+//!
+//! assert_eq!(parse_u32(b"0000BEEF").unwrap(), 0xbeef);
+//! assert_eq!(parse_u32(b"beef").unwrap(), 0xbeef);
+//! assert_eq!(parse_u64(b"1f").unwrap(), 0x1f);
+//!
+//! assert_eq!(&format_u64(0x1f, AsciiCase::Lower), b"000000000000001f");
+//!
+//! let mut buf = [0u8; 16];
+//! assert_eq!(format_u64_trimmed(0x1f, AsciiCase::Upper, buf.as_out()), b"1F");
+//! ```
+
+use crate::decode::decode_fallback;
+use crate::encode::encode_fallback;
+use crate::error::locate_hex;
+use crate::{AsciiCase, Error, Out};
+
+use vsimd::tools::slice_mut;
+use vsimd::vector::V128;
+use vsimd::SIMD256;
+
+use core::mem::MaybeUninit;
+
+/// Decodes 16 hex characters to a big-endian `u64`.
+#[inline(always)]
+pub(crate) unsafe fn decode_u64_fallback(src: *const u8) -> Result<u64, Error> {
+    let mut buf = [0u8; 8];
+    decode_fallback(src, 16, buf.as_mut_ptr())?;
+    Ok(u64::from_be_bytes(buf))
+}
+
+#[inline(always)]
+pub(crate) unsafe fn decode_u64_simd<S: SIMD256>(s: S, src: *const u8) -> Result<u64, Error> {
+    let x = s.v128_load_unaligned(src);
+    let y = try_!(vsimd::hex::decode_ascii16(s, x));
+    Ok(u64::from_be(y.to_u64()))
+}
+
+/// Encodes a `u64` to 16 big-endian hex characters.
+#[inline(always)]
+pub(crate) unsafe fn encode_u64_fallback(x: u64, dst: *mut u8, case: AsciiCase) {
+    encode_fallback(x.to_be_bytes().as_ptr(), 8, dst, case);
+}
+
+#[inline(always)]
+pub(crate) unsafe fn encode_u64_simd<S: SIMD256>(s: S, x: u64, dst: *mut u8, case: AsciiCase) {
+    let lut = match case {
+        AsciiCase::Lower => vsimd::hex::ENCODE_LOWER_LUT,
+        AsciiCase::Upper => vsimd::hex::ENCODE_UPPER_LUT,
+    };
+    let mut bytes = [0u8; 16];
+    bytes[..8].copy_from_slice(&x.to_be_bytes());
+    let (y, _) = vsimd::hex::encode_bytes16(s, V128::from_bytes(bytes), lut).to_v128x2();
+    s.v128_store_unaligned(dst, y);
+}
+
+/// Pads `src` to `N` characters with leading zeros.
+#[inline(always)]
+fn pad<const N: usize>(src: &[u8]) -> Result<[u8; N], Error> {
+    ensure!(!src.is_empty() && src.len() <= N, InvalidLength);
+    let mut buf = [b'0'; N];
+    buf[N - src.len()..].copy_from_slice(src);
+    Ok(buf)
+}
+
+/// Parses a hex string of at most 16 characters.
+#[inline(always)]
+fn parse16(src: &[u8]) -> Result<u64, Error> {
+    let buf: [u8; 16] = pad(src)?;
+    unsafe { crate::multiversion::decode_u64::auto(buf.as_ptr()).map_err(|_| locate_hex(src)) }
+}
+
+/// Parses a hex string to a `u32` case-insensitively.
+///
+/// `src` can be fixed-width (8 characters) or variable-width (1 to 8 characters).
+///
+/// # Errors
+/// This function returns `Err` if
+/// + `src` is empty or longer than 8 characters.
+/// + the content of `src` is invalid.
+#[inline]
+pub fn parse_u32(src: &[u8]) -> Result<u32, Error> {
+    ensure!(src.len() <= 8, InvalidLength);
+    Ok(parse16(src)? as u32)
+}
+
+/// Parses a hex string to a `u64` case-insensitively.
+///
+/// `src` can be fixed-width (16 characters) or variable-width (1 to 16 characters).
+///
+/// # Errors
+/// This function returns `Err` if
+/// + `src` is empty or longer than 16 characters.
+/// + the content of `src` is invalid.
+#[inline]
+pub fn parse_u64(src: &[u8]) -> Result<u64, Error> {
+    parse16(src)
+}
+
+/// Parses a hex string to a `u128` case-insensitively.
+///
+/// `src` can be fixed-width (32 characters) or variable-width (1 to 32 characters).
+///
+/// # Errors
+/// This function returns `Err` if
+/// + `src` is empty or longer than 32 characters.
+/// + the content of `src` is invalid.
+#[inline]
+pub fn parse_u128(src: &[u8]) -> Result<u128, Error> {
+    let buf: [u8; 32] = pad(src)?;
+    unsafe {
+        let hi = crate::multiversion::decode_u64::auto(buf.as_ptr());
+        let lo = crate::multiversion::decode_u64::auto(buf.as_ptr().add(16));
+        match (hi, lo) {
+            (Ok(hi), Ok(lo)) => Ok(u128::from(hi) << 64 | u128::from(lo)),
+            _ => Err(locate_hex(src)),
+        }
+    }
+}
+
+/// Formats a `u64` to 16 hex characters with leading zeros.
+///
+/// `case` specifies the ascii case of output.
+#[inline]
+#[must_use]
+pub fn format_u64(x: u64, case: AsciiCase) -> [u8; 16] {
+    let mut ans = MaybeUninit::<[u8; 16]>::uninit();
+    unsafe {
+        crate::multiversion::encode_u64::auto(x, ans.as_mut_ptr().cast(), case);
+        ans.assume_init()
+    }
+}
+
+/// Formats a `u64` to hex characters without leading zeros.
+///
+/// `case` specifies the ascii case of output. Zero is formatted as `0`.
+#[inline]
+#[must_use]
+pub fn format_u64_trimmed(x: u64, case: AsciiCase, mut dst: Out<'_, [u8; 16]>) -> &mut [u8] {
+    let n = (16 - x.leading_zeros() as usize / 4).max(1);
+    unsafe {
+        let dst = dst.as_mut_ptr().cast::<u8>();
+        crate::multiversion::encode_u64::auto(x, dst, case);
+        dst.copy_from(dst.add(16 - n), n);
+        slice_mut(dst, n)
+    }
+}
@@ -0,0 +1,277 @@
+//! Streaming hex encoding and decoding over [`std::io`].
+//!
+//! # Examples
+//!
+//! ```
+//! use hex_simd::io::{DecoderReader, EncoderWriter};
+//! use hex_simd::AsciiCase;
+//! use std::io::{Read, Write};
+//!
+//! let mut writer = EncoderWriter::new(Vec::new(), AsciiCase::Lower);
+//! writer.write_all(b"Hello world!").unwrap();
+//! let encoded = writer.into_inner().unwrap();
+//! assert_eq!(encoded, b"48656c6c6f20776f726c6421");
+//!
+//! let mut reader = DecoderReader::new(encoded.as_slice());
+//! let mut decoded = Vec::new();
+//! reader.read_to_end(&mut decoded).unwrap();
+//! assert_eq!(decoded, b"Hello world!");
+//! ```
+
+use crate::{AsOut, AsciiCase};
+
+use vsimd::tools::slice_parts;
+
+use std::io::{self, Read, Write};
+use std::mem::ManuallyDrop;
+use std::{fmt, ptr};
+
+const BUF_SIZE: usize = 8192;
+
+/// A writer which encodes bytes to a hex string and writes it to the inner writer.
+///
+/// The encoded data is buffered. Call [`Write::flush`] or [`EncoderWriter::into_inner`]
+/// to make sure that all data reaches the inner writer.
+/// Errors happening when the writer is dropped are ignored.
+pub struct EncoderWriter<W: Write> {
+    inner: W,
+    case: AsciiCase,
+    buf: Vec<u8>,
+    panicked: bool,
+}
+
+impl<W: Write> EncoderWriter<W> {
+    /// Creates a new encoder writer.
+    ///
+    /// `case` specifies the ascii case of output.
+    #[inline]
+    #[must_use]
+    pub fn new(inner: W, case: AsciiCase) -> Self {
+        Self {
+            inner,
+            case,
+            buf: Vec::with_capacity(BUF_SIZE),
+            panicked: false,
+        }
+    }
+
+    /// Returns a reference to the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner writer.
+    ///
+    /// Writing to the inner writer directly may corrupt the output
+    /// if there is encoded data which has not been flushed.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Writes all buffered data and returns the inner writer.
+    ///
+    /// # Errors
+    /// This function returns `Err` if the inner writer fails to write the buffered data.
+    #[inline]
+    pub fn into_inner(mut self) -> io::Result<W> {
+        self.flush_buf()?;
+        let this = ManuallyDrop::new(self);
+        unsafe {
+            drop(ptr::read(&this.buf));
+            Ok(ptr::read(&this.inner))
+        }
+    }
+
+    fn flush_buf(&mut self) -> io::Result<()> {
+        let mut written = 0;
+        let mut ans = Ok(());
+        while written < self.buf.len() {
+            self.panicked = true;
+            let r = self.inner.write(&self.buf[written..]);
+            self.panicked = false;
+            match r {
+                Ok(0) => {
+                    ans = Err(io::Error::new(
+                        io::ErrorKind::WriteZero,
+                        "failed to write the buffered data",
+                    ));
+                    break;
+                }
+                Ok(n) => written += n,
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => {
+                    ans = Err(e);
+                    break;
+                }
+            }
+        }
+        self.buf.drain(..written);
+        ans
+    }
+}
+
+impl<W: Write> Write for EncoderWriter<W> {
+    #[inline]
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        if data.is_empty() {
+            return Ok(0);
+        }
+        if self.buf.capacity() - self.buf.len() < 2 {
+            self.flush_buf()?;
+        }
+
+        let free = self.buf.capacity() - self.buf.len();
+        let n = data.len().min(free / 2);
+        unsafe {
+            let (src, len) = slice_parts(&data[..n]);
+            let dst = self.buf.as_mut_ptr().add(self.buf.len());
+            crate::multiversion::encode::auto(src, len, dst, self.case);
+            self.buf.set_len(self.buf.len() + n * 2);
+        }
+        Ok(n)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        self.flush_buf()?;
+        self.inner.flush()
+    }
+}
+
+impl<W: Write> Drop for EncoderWriter<W> {
+    #[inline]
+    fn drop(&mut self) {
+        if !self.panicked {
+            let _ = self.flush_buf();
+        }
+    }
+}
+
+impl<W: Write + fmt::Debug> fmt::Debug for EncoderWriter<W> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EncoderWriter")
+            .field("inner", &self.inner)
+            .field("case", &self.case)
+            .field("buffered", &self.buf.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A reader which reads a hex string from the inner reader and decodes it to bytes case-insensitively.
+///
+/// A trailing hex character which does not form a complete byte is kept
+/// until the next character arrives. If the inner reader reaches EOF with
+/// a pending character, the read fails with [`io::ErrorKind::UnexpectedEof`].
+///
+/// Invalid hex characters produce an error of kind [`io::ErrorKind::InvalidData`]
+/// wrapping a [`crate::Error`], whose offset is counted from the start of the stream.
+pub struct DecoderReader<R: Read> {
+    inner: R,
+    buf: Box<[u8]>,
+    pos: usize,
+    end: usize,
+    consumed: usize,
+}
+
+impl<R: Read> DecoderReader<R> {
+    /// Creates a new decoder reader.
+    #[inline]
+    #[must_use]
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            buf: vec![0; BUF_SIZE].into_boxed_slice(),
+            pos: 0,
+            end: 0,
+            consumed: 0,
+        }
+    }
+
+    /// Returns a reference to the inner reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner reader.
+    ///
+    /// Reading from the inner reader directly may corrupt the output
+    /// if there is buffered data which has not been decoded.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the inner reader.
+    ///
+    /// Any buffered data which has not been decoded is discarded.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Reads more hex characters. Returns `false` on EOF.
+    fn fill_buf(&mut self) -> io::Result<bool> {
+        if self.pos > 0 {
+            self.buf.copy_within(self.pos..self.end, 0);
+            self.end -= self.pos;
+            self.pos = 0;
+        }
+        loop {
+            match self.inner.read(&mut self.buf[self.end..]) {
+                Ok(0) => return Ok(false),
+                Ok(n) => {
+                    self.end += n;
+                    return Ok(true);
+                }
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+impl<R: Read> Read for DecoderReader<R> {
+    #[inline]
+    fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
+        if out.is_empty() {
+            return Ok(0);
+        }
+
+        while self.end - self.pos < 2 {
+            if !self.fill_buf()? {
+                if self.end == self.pos {
+                    return Ok(0);
+                }
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "hex string has an odd length",
+                ));
+            }
+        }
+
+        let n = ((self.end - self.pos) / 2).min(out.len());
+        let src = &self.buf[self.pos..self.pos + n * 2];
+        match crate::decode(src, out[..n].as_out()) {
+            Ok(_) => {
+                self.pos += n * 2;
+                self.consumed += n * 2;
+                Ok(n)
+            }
+            Err(e) => Err(io::Error::new(io::ErrorKind::InvalidData, e.add_offset(self.consumed))),
+        }
+    }
+}
+
+impl<R: Read + fmt::Debug> fmt::Debug for DecoderReader<R> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecoderReader")
+            .field("inner", &self.inner)
+            .field("buffered", &(self.end - self.pos))
+            .finish_non_exhaustive()
+    }
+}
@@ -47,23 +47,71 @@ extern crate alloc;
 
 #[macro_use]
 mod error;
-pub use self::error::Error;
+use self::error::{locate, locate_hex};
+pub use self::error::{Error, ErrorKind};
 
 mod check;
 mod decode;
 mod encode;
+mod separated;
+mod strict;
+
+mod forgiving;
+pub use self::forgiving::{forgiving_decode, forgiving_decode_inplace};
+
+#[cfg(feature = "alloc")]
+pub use self::forgiving::forgiving_decode_to_vec;
+
+mod display;
+pub use self::display::{display, HexDisplay};
+
+mod words;
+pub use self::words::{decode_words, encode_words, Endian, Word};
+
+mod options;
+pub use self::options::{decode_with, DecodeOptions, OddLength, Prefix};
 
 mod multiversion;
 
+pub mod int;
+
 #[cfg(feature = "alloc")]
 mod heap;
 
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+#[cfg(feature = "serde")]
+pub mod serde;
+
+#[cfg(feature = "parallel")]
+mod parallel;
+#[cfg(feature = "parallel")]
+pub use self::parallel::{par_check, par_decode, par_decode_to_vec, par_encode, par_encode_to_string};
+
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+pub mod dump;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+pub mod ihex;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+pub mod srec;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+pub mod io;
+
 pub use outref::{AsOut, Out};
 pub use vsimd::ascii::AsciiCase;
 
 // -------------------------------------------------------------------------------------------------
 
-use vsimd::tools::{slice_mut, slice_parts};
+use vsimd::hex::unhex;
+use vsimd::tools::{slice, slice_mut, slice_parts};
+
+use core::mem::MaybeUninit;
 
 #[cfg(feature = "alloc")]
 use alloc::{string::String, vec::Vec};
@@ -85,7 +133,7 @@ pub const fn encoded_length(n: usize) -> usize {
 /// This function returns `Err` if `n` is not even.
 #[inline]
 pub fn decoded_length(n: usize) -> Result<usize, Error> {
-    ensure!(n % 2 == 0);
+    ensure!(n % 2 == 0, InvalidLength);
     Ok(n / 2)
 }
 
@@ -99,7 +147,7 @@ pub fn decoded_length(n: usize) -> Result<usize, Error> {
 /// + `src` must be valid for reading `len` bytes.
 #[inline]
 pub unsafe fn check_raw(src: *const u8, len: usize) -> Result<(), Error> {
-    crate::multiversion::check::auto(src, len)
+    crate::multiversion::check::auto(src, len).map_err(|_| locate_hex(slice(src, len)))
 }
 
 /// Encodes bytes to a hex string with raw pointers.
@@ -130,8 +178,15 @@ pub unsafe fn encode_raw(src: *const u8, len: usize, dst: *mut u8, case: AsciiCa
 ///   In other words, the function supports either "copy mode" or "inplace mode".
 #[inline]
 pub unsafe fn decode_raw(src: *const u8, len: usize, dst: *mut u8) -> Result<usize, Error> {
-    ensure!(len % 2 == 0);
-    crate::multiversion::decode::auto(src, len, dst)?;
+    ensure!(len % 2 == 0, InvalidLength);
+    crate::multiversion::decode::auto(src, len, dst).map_err(|_| {
+        // the input has been overwritten in inplace mode
+        if core::ptr::eq(src, dst) {
+            Error::new()
+        } else {
+            locate_hex(slice(src, len))
+        }
+    })?;
     Ok(len / 2)
 }
 
@@ -144,7 +199,7 @@ pub unsafe fn decode_raw(src: *const u8, len: usize, dst: *mut u8) -> Result<usi
 #[inline]
 pub fn check(data: &[u8]) -> Result<(), Error> {
     let (src, len) = slice_parts(data);
-    unsafe { crate::multiversion::check::auto(src, len) }
+    unsafe { crate::multiversion::check::auto(src, len).map_err(|_| locate_hex(data)) }
 }
 
 /// Encodes bytes to a hex string.
@@ -155,7 +210,7 @@ pub fn check(data: &[u8]) -> Result<(), Error> {
 /// This function returns `Err` if the length of `dst` is not enough.
 #[inline]
 pub fn encode<'d>(src: &[u8], mut dst: Out<'d, [u8]>, case: AsciiCase) -> Result<&'d mut [u8], Error> {
-    ensure!(dst.len() / 2 >= src.len());
+    ensure!(dst.len() / 2 >= src.len(), BufferTooSmall);
     unsafe {
         let (src, len) = slice_parts(src);
         let dst = dst.as_mut_ptr();
@@ -172,13 +227,13 @@ pub fn encode<'d>(src: &[u8], mut dst: Out<'d, [u8]>, case: AsciiCase) -> Result
 /// + the content of `src` is invalid.
 #[inline]
 pub fn decode<'d>(src: &[u8], mut dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Error> {
-    ensure!(src.len() % 2 == 0 && dst.len() >= src.len() / 2);
+    ensure!(src.len() % 2 == 0, InvalidLength);
+    ensure!(dst.len() >= src.len() / 2, BufferTooSmall);
 
     let len = src.len();
     let dst = dst.as_mut_ptr();
-    let src = src.as_ptr();
     unsafe {
-        crate::multiversion::decode::auto(src, len, dst)?;
+        crate::multiversion::decode::auto(src.as_ptr(), len, dst).map_err(|_| locate_hex(src))?;
         Ok(slice_mut(dst, len / 2))
     }
 }
@@ -189,7 +244,7 @@ pub fn decode<'d>(src: &[u8], mut dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Er
 /// This function returns `Err` if the content of `data` is invalid.
 #[inline]
 pub fn decode_inplace(data: &mut [u8]) -> Result<&mut [u8], Error> {
-    ensure!(data.len() % 2 == 0);
+    ensure!(data.len() % 2 == 0, InvalidLength);
     unsafe {
         let len = data.len();
         let dst: *mut u8 = data.as_mut_ptr();
@@ -199,6 +254,96 @@ pub fn decode_inplace(data: &mut [u8]) -> Result<&mut [u8], Error> {
     }
 }
 
+/// Checks whether `data` is a hex string in the specified ascii case.
+///
+/// Letters of the other case are rejected.
+///
+/// # Errors
+/// This function returns `Err` if any byte in `data` is not a hex character of `case`.
+#[inline]
+pub fn check_strict(data: &[u8], case: AsciiCase) -> Result<(), Error> {
+    let (src, len) = slice_parts(data);
+    unsafe { crate::multiversion::check_strict::auto(src, len, case).map_err(|_| strict::locate(data, case)) }
+}
+
+/// Decodes a hex string in the specified ascii case to bytes.
+///
+/// Letters of the other case are rejected.
+///
+/// # Errors
+/// This function returns `Err` if
+/// + the length of `dst` is not enough.
+/// + the content of `src` is invalid.
+#[inline]
+pub fn decode_strict<'d>(src: &[u8], mut dst: Out<'d, [u8]>, case: AsciiCase) -> Result<&'d mut [u8], Error> {
+    ensure!(src.len() % 2 == 0, InvalidLength);
+    ensure!(dst.len() >= src.len() / 2, BufferTooSmall);
+
+    let len = src.len();
+    let dst = dst.as_mut_ptr();
+    unsafe {
+        crate::multiversion::decode_strict::auto(src.as_ptr(), len, dst, case)
+            .map_err(|_| strict::locate(src, case))?;
+        Ok(slice_mut(dst, len / 2))
+    }
+}
+
+/// Encodes bytes to a hex string with `sep` between every two encoded bytes.
+///
+/// For example, `[0xaa, 0xbb, 0xcc]` with `b':'` is encoded to `aa:bb:cc`.
+/// The encoded length is `n * 3 - 1` for `n > 0` bytes.
+///
+/// `case` specifies the ascii case of output.
+///
+/// # Errors
+/// This function returns `Err` if the length of `dst` is not enough.
+#[inline]
+pub fn encode_separated<'d>(
+    src: &[u8],
+    mut dst: Out<'d, [u8]>,
+    sep: u8,
+    case: AsciiCase,
+) -> Result<&'d mut [u8], Error> {
+    if src.is_empty() {
+        return Ok(&mut []);
+    }
+    ensure!(
+        dst.len() / 3 >= src.len() - 1 && dst.len() >= src.len() * 3 - 1,
+        BufferTooSmall
+    );
+    unsafe {
+        let (src, len) = slice_parts(src);
+        let dst = dst.as_mut_ptr();
+        crate::multiversion::encode_separated::auto(src, len, dst, sep, case);
+        Ok(slice_mut(dst, len * 3 - 1))
+    }
+}
+
+/// Decodes a hex string with `sep` between every two encoded bytes case-insensitively.
+///
+/// For example, `aa:bb:cc` with `b':'` is decoded to `[0xaa, 0xbb, 0xcc]`.
+///
+/// # Errors
+/// This function returns `Err` if
+/// + the length of `dst` is not enough.
+/// + the separators do not appear exactly at every third position.
+/// + the content of `src` is invalid.
+#[inline]
+pub fn decode_separated<'d>(src: &[u8], mut dst: Out<'d, [u8]>, sep: u8) -> Result<&'d mut [u8], Error> {
+    if src.is_empty() {
+        return Ok(&mut []);
+    }
+    ensure!(src.len() % 3 == 2, InvalidLength);
+    ensure!(dst.len() > src.len() / 3, BufferTooSmall);
+    unsafe {
+        let len = src.len();
+        let dst = dst.as_mut_ptr();
+        crate::multiversion::decode_separated::auto(src.as_ptr(), len, dst, sep)
+            .map_err(|_| locate(src, |i, x| if i % 3 == 2 { x == sep } else { unhex(x) != 0xff }))?;
+        Ok(slice_mut(dst, len / 3 + 1))
+    }
+}
+
 /// Encodes bytes to a hex string and returns [`&mut str`](str).
 ///
 /// `case` specifies the ascii case of output.
@@ -211,6 +356,63 @@ pub fn encode_as_str<'d>(src: &[u8], dst: Out<'d, [u8]>, case: AsciiCase) -> Res
     Ok(unsafe { core::str::from_utf8_unchecked_mut(ans) })
 }
 
+struct ArrayLength<const N: usize, const M: usize>;
+
+impl<const N: usize, const M: usize> ArrayLength<N, M> {
+    const ENCODED: () = assert!(N <= usize::MAX / 2 && M == N * 2, "M must be equal to 2 * N");
+}
+
+/// Encodes a byte array to a hex string array on the stack.
+///
+/// `case` specifies the ascii case of output.
+/// `M` must be equal to `2 * N`, which is checked at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use hex_simd::AsciiCase;
+///
+/// let hex: [u8; 8] = hex_simd::encode_array(&[0xde, 0xad, 0xbe, 0xef], AsciiCase::Lower);
+/// assert_eq!(&hex, b"deadbeef");
+/// ```
+#[inline]
+#[must_use]
+pub fn encode_array<const N: usize, const M: usize>(src: &[u8; N], case: AsciiCase) -> [u8; M] {
+    #[allow(clippy::let_unit_value)]
+    let () = ArrayLength::<N, M>::ENCODED;
+
+    let mut ans = MaybeUninit::<[u8; M]>::uninit();
+    unsafe {
+        crate::multiversion::encode::auto(src.as_ptr(), N, ans.as_mut_ptr().cast(), case);
+        ans.assume_init()
+    }
+}
+
+/// Decodes a hex string to a byte array on the stack case-insensitively.
+///
+/// # Examples
+///
+/// ```
+/// let bytes: [u8; 4] = hex_simd::decode_array(b"DEADbeef").unwrap();
+/// assert_eq!(bytes, [0xde, 0xad, 0xbe, 0xef]);
+/// ```
+///
+/// # Errors
+/// This function returns `Err` if
+/// + the length of `src` is not `2 * N`.
+/// + the content of `src` is invalid.
+#[inline]
+pub fn decode_array<const N: usize>(src: &[u8]) -> Result<[u8; N], Error> {
+    ensure!(src.len() / 2 == N && src.len() % 2 == 0, InvalidLength);
+
+    let mut ans = MaybeUninit::<[u8; N]>::uninit();
+    unsafe {
+        crate::multiversion::decode::auto(src.as_ptr(), src.len(), ans.as_mut_ptr().cast())
+            .map_err(|_| locate_hex(src))?;
+        Ok(ans.assume_init())
+    }
+}
+
 /// Types that can be decoded from a hex string.
 pub trait FromHexDecode: Sized {
     /// Decodes a hex string to bytes case-insensitively and returns the self type.
@@ -220,6 +422,13 @@ pub trait FromHexDecode: Sized {
     fn from_hex_decode(data: &[u8]) -> Result<Self, Error>;
 }
 
+impl<const N: usize> FromHexDecode for [u8; N] {
+    #[inline]
+    fn from_hex_decode(data: &[u8]) -> Result<Self, Error> {
+        decode_array(data)
+    }
+}
+
 /// Types that can represent a hex string.
 pub trait FromHexEncode: Sized {
     /// Encodes bytes to a hex string and returns the self type.
@@ -28,3 +28,84 @@ vsimd::dispatch!(
     targets     = {"avx2", "ssse3", "sse2", "neon", "simd128"},
     fastest     = {"avx2", "neon", "simd128"},
 );
+
+vsimd::dispatch!(
+    name        = {encode_separated},
+    signature   = {pub unsafe fn(src: *const u8, len: usize, dst: *mut u8, sep: u8, case: AsciiCase) -> ()},
+    fallback    = {crate::separated::encode_separated_fallback},
+    simd        = {crate::separated::encode_separated_simd},
+    targets     = {"avx2", "ssse3", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {decode_separated},
+    signature   = {pub unsafe fn(src: *const u8, len: usize, dst: *mut u8, sep: u8) -> Result<(), Error>},
+    fallback    = {crate::separated::decode_separated_fallback},
+    simd        = {crate::separated::decode_separated_simd},
+    targets     = {"avx2", "ssse3", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {check_strict},
+    signature   = {pub unsafe fn(src: *const u8, len: usize, case: AsciiCase) -> Result<(), Error>},
+    fallback    = {crate::strict::check_strict_fallback},
+    simd        = {crate::strict::check_strict_simd},
+    targets     = {"avx2", "sse2", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {decode_strict},
+    signature   = {pub unsafe fn(src: *const u8, len: usize, dst: *mut u8, case: AsciiCase) -> Result<(), Error>},
+    fallback    = {crate::strict::decode_strict_fallback},
+    simd        = {crate::strict::decode_strict_simd},
+    targets     = {"avx2", "ssse3", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {decode_u64},
+    signature   = {pub unsafe fn(src: *const u8) -> Result<u64, Error>},
+    fallback    = {crate::int::decode_u64_fallback},
+    simd        = {crate::int::decode_u64_simd},
+    targets     = {"avx2", "ssse3", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {encode_u64},
+    signature   = {pub unsafe fn(x: u64, dst: *mut u8, case: AsciiCase) -> ()},
+    fallback    = {crate::int::encode_u64_fallback},
+    simd        = {crate::int::encode_u64_simd},
+    targets     = {"avx2", "ssse3", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {encode_swapped},
+    signature   = {pub unsafe fn(src: *const u8, len: usize, dst: *mut u8, width: usize, case: AsciiCase) -> ()},
+    fallback    = {crate::words::encode_swapped_fallback},
+    simd        = {crate::words::encode_swapped_simd},
+    targets     = {"avx2", "ssse3", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {decode_swapped},
+    signature   = {pub unsafe fn(src: *const u8, len: usize, dst: *mut u8, width: usize) -> Result<(), Error>},
+    fallback    = {crate::words::decode_swapped_fallback},
+    simd        = {crate::words::decode_swapped_simd},
+    targets     = {"avx2", "ssse3", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {find_non_ascii_whitespace},
+    signature   = {pub unsafe fn(src: *const u8, len: usize) -> usize},
+    fallback    = {vsimd::ascii::find_non_ascii_whitespace_fallback},
+    simd        = {vsimd::ascii::find_non_ascii_whitespace_simd},
+    targets     = {"avx2", "sse2", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
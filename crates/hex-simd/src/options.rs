@@ -0,0 +1,198 @@
+use crate::error::{locate, ErrorKind};
+use crate::{Error, Out};
+
+use vsimd::hex::unhex;
+use vsimd::tools::slice_mut;
+
+/// How a `0x` prefix is handled by [`decode_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Prefix {
+    /// A prefix is treated as invalid content.
+    #[default]
+    Deny,
+    /// A `0x` or `0X` prefix is skipped if present.
+    Allow0x,
+    /// A `0x` or `0X` prefix must be present.
+    Require0x,
+}
+
+/// How an odd number of hex characters is handled by [`decode_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OddLength {
+    /// An odd length is an error.
+    #[default]
+    Reject,
+    /// The hex string is decoded as if it started with an extra `0`.
+    ///
+    /// For example, `abc` is decoded to `[0x0a, 0xbc]`.
+    LeadingZeroNibble,
+}
+
+/// Options of [`decode_with`].
+///
+/// The default options are the same as [`decode`](crate::decode).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DecodeOptions {
+    /// How a `0x` prefix is handled.
+    pub prefix: Prefix,
+    /// How an odd number of hex characters is handled.
+    pub odd: OddLength,
+    /// Whether ascii whitespace is skipped.
+    ///
+    /// Whitespace may also appear before the prefix.
+    pub ignore_whitespace: bool,
+}
+
+#[inline(always)]
+fn strip_0x(src: &[u8]) -> Option<&[u8]> {
+    match src {
+        [b'0', b'x' | b'X', rest @ ..] => Some(rest),
+        _ => None,
+    }
+}
+
+struct Decoder {
+    dst: *mut u8,
+    pending: Option<u8>,
+}
+
+impl Decoder {
+    /// Decodes a segment of hex characters.
+    /// A nibble left over by a segment is combined with the first character of the next segment.
+    #[inline(always)]
+    unsafe fn feed(&mut self, mut seg: &[u8]) -> Result<(), Error> {
+        if seg.is_empty() {
+            return Ok(());
+        }
+
+        if let Some(hi) = self.pending.take() {
+            let lo = unhex(seg[0]);
+            ensure!(lo != 0xff);
+            self.dst.write(hi << 4 | lo);
+            self.dst = self.dst.add(1);
+            seg = &seg[1..];
+        }
+
+        let len = seg.len() / 2 * 2;
+        crate::multiversion::decode::auto(seg.as_ptr(), len, self.dst)?;
+        self.dst = self.dst.add(len / 2);
+
+        if seg.len() % 2 == 1 {
+            let hi = unhex(seg[len]);
+            ensure!(hi != 0xff);
+            self.pending = Some(hi);
+        }
+        Ok(())
+    }
+}
+
+/// Decodes a hex string to bytes case-insensitively with options.
+///
+/// # Examples
+///
+/// ```
+/// use hex_simd::{AsOut, DecodeOptions, OddLength, Prefix};
+///
+/// let options = DecodeOptions {
+///     prefix: Prefix::Allow0x,
+///     odd: OddLength::LeadingZeroNibble,
+///     ignore_whitespace: true,
+/// };
+///
+/// let mut buf = [0u8; 4];
+/// let ans = hex_simd::decode_with(b"0xabc\n de", buf.as_mut_slice().as_out(), options).unwrap();
+/// assert_eq!(ans, [0x0a, 0xbc, 0xde]);
+/// ```
+///
+/// # Errors
+/// This function returns `Err` if
+/// + the length of `dst` is not enough.
+/// + the content of `src` is invalid.
+/// + the number of hex characters is odd and `options.odd` is [`OddLength::Reject`].
+#[inline]
+pub fn decode_with<'d>(src: &[u8], dst: Out<'d, [u8]>, options: DecodeOptions) -> Result<&'d mut [u8], Error> {
+    decode_with_unlocated(src, dst, options).map_err(|e| {
+        if let ErrorKind::Invalid = e.kind() {
+            locate_with(src, options)
+        } else {
+            e
+        }
+    })
+}
+
+/// Finds the first invalid character after the prefix.
+#[cold]
+#[inline(never)]
+fn locate_with(src: &[u8], options: DecodeOptions) -> Error {
+    let mut start = 0;
+    if options.ignore_whitespace {
+        start = src.iter().position(|x| !x.is_ascii_whitespace()).unwrap_or(src.len());
+    }
+    if options.prefix != Prefix::Deny && strip_0x(&src[start..]).is_some() {
+        start += 2;
+    }
+    locate(src, |i, x| {
+        i < start || unhex(x) != 0xff || (options.ignore_whitespace && x.is_ascii_whitespace())
+    })
+}
+
+#[inline(always)]
+fn decode_with_unlocated<'d>(
+    src: &[u8],
+    mut dst: Out<'d, [u8]>,
+    options: DecodeOptions,
+) -> Result<&'d mut [u8], Error> {
+    let input_len = src.len();
+    let mut src = src;
+
+    if options.ignore_whitespace {
+        let start = src.iter().position(|x| !x.is_ascii_whitespace()).unwrap_or(src.len());
+        src = &src[start..];
+    }
+
+    match options.prefix {
+        Prefix::Deny => {}
+        Prefix::Allow0x => src = strip_0x(src).unwrap_or(src),
+        Prefix::Require0x => {
+            if let Some(rest) = strip_0x(src) {
+                src = rest;
+            } else {
+                ensure!(src.len() >= 2, InvalidLength);
+                let offset = input_len - src.len() + usize::from(src[0] == b'0');
+                let byte = if src[0] == b'0' { src[1] } else { src[0] };
+                return Err(Error::from_kind(ErrorKind::InvalidCharacter { offset, byte }));
+            }
+        }
+    }
+
+    let digits = if options.ignore_whitespace {
+        src.iter().filter(|x| !x.is_ascii_whitespace()).count()
+    } else {
+        src.len()
+    };
+
+    let odd = digits % 2 == 1;
+    ensure!(!odd || options.odd == OddLength::LeadingZeroNibble, InvalidLength);
+
+    let n = (digits + 1) / 2;
+    ensure!(dst.len() >= n, BufferTooSmall);
+
+    unsafe {
+        let dst = dst.as_mut_ptr();
+        let mut decoder = Decoder {
+            dst,
+            pending: if odd { Some(0) } else { None },
+        };
+
+        if options.ignore_whitespace {
+            for seg in src.split(u8::is_ascii_whitespace) {
+                decoder.feed(seg)?;
+            }
+        } else {
+            decoder.feed(src)?;
+        }
+        debug_assert!(decoder.pending.is_none());
+
+        Ok(slice_mut(dst, n))
+    }
+}
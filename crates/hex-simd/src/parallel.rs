@@ -0,0 +1,124 @@
+use crate::error::locate_hex;
+use crate::{AsciiCase, Error, Out};
+
+use vsimd::tools::{alloc_uninit_bytes, assume_init, slice_mut};
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use rayon::prelude::{IndexedParallelIterator, ParallelIterator};
+use rayon::slice::{ParallelSlice, ParallelSliceMut};
+
+/// Returns the number of bytes per chunk, or `None` if `len` is too short to be worth splitting.
+#[inline(always)]
+fn chunk_size(len: usize) -> Option<usize> {
+    let p = rayon::current_num_threads();
+    if p < 2 || len < p * 4096 {
+        return None;
+    }
+    Some((len + p - 1) / p)
+}
+
+/// Encodes bytes to a hex string in parallel.
+///
+/// `case` specifies the ascii case of output.
+///
+/// # Errors
+/// This function returns `Err` if the length of `dst` is not enough.
+#[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+#[inline]
+pub fn par_encode<'d>(src: &[u8], dst: Out<'d, [u8]>, case: AsciiCase) -> Result<&'d mut [u8], Error> {
+    let chunk = match chunk_size(src.len()) {
+        Some(chunk) => chunk,
+        None => return crate::encode(src, dst, case),
+    };
+    ensure!(dst.len() / 2 >= src.len(), BufferTooSmall);
+
+    let dst = unsafe { &mut dst.into_uninit_slice()[..src.len() * 2] };
+    src.par_chunks(chunk)
+        .zip(dst.par_chunks_mut(chunk * 2))
+        .for_each(|(s, d)| unsafe {
+            crate::multiversion::encode::auto(s.as_ptr(), s.len(), d.as_mut_ptr().cast(), case);
+        });
+
+    unsafe { Ok(slice_mut(dst.as_mut_ptr().cast(), dst.len())) }
+}
+
+/// Decodes a hex string to bytes case-insensitively in parallel.
+///
+/// `src` is split on even boundaries so that no byte is shared by two chunks.
+///
+/// # Errors
+/// This function returns `Err` if
+/// + the length of `dst` is not enough.
+/// + the content of `src` is invalid.
+#[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+#[inline]
+pub fn par_decode<'d>(src: &[u8], dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Error> {
+    let chunk = match chunk_size(src.len() / 2) {
+        Some(chunk) => chunk,
+        None => return crate::decode(src, dst),
+    };
+    ensure!(src.len() % 2 == 0, InvalidLength);
+    ensure!(dst.len() >= src.len() / 2, BufferTooSmall);
+
+    let dst = unsafe { &mut dst.into_uninit_slice()[..src.len() / 2] };
+    src.par_chunks(chunk * 2)
+        .zip(dst.par_chunks_mut(chunk))
+        .try_for_each(|(s, d)| unsafe { crate::multiversion::decode::auto(s.as_ptr(), s.len(), d.as_mut_ptr().cast()) })
+        .map_err(|_| locate_hex(src))?;
+
+    unsafe { Ok(slice_mut(dst.as_mut_ptr().cast(), dst.len())) }
+}
+
+/// Checks whether `data` is a hex string in parallel.
+///
+/// # Errors
+/// This function returns `Err` if any byte in `data` is not a hex character.
+#[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+#[inline]
+pub fn par_check(data: &[u8]) -> Result<(), Error> {
+    let chunk = match chunk_size(data.len()) {
+        Some(chunk) => chunk,
+        None => return crate::check(data),
+    };
+    data.par_chunks(chunk)
+        .try_for_each(|s| unsafe { crate::multiversion::check::auto(s.as_ptr(), s.len()) })
+        .map_err(|_| locate_hex(data))
+}
+
+/// Encodes bytes to a hex string in parallel.
+#[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+#[inline]
+#[must_use]
+pub fn par_encode_to_string(data: impl AsRef<[u8]>, case: AsciiCase) -> String {
+    let src = data.as_ref();
+    if src.is_empty() {
+        return String::new();
+    }
+    let m = crate::encoded_length(src.len());
+    unsafe {
+        let mut buf = alloc_uninit_bytes(m);
+        let _ = par_encode(src, Out::from_uninit_slice(&mut buf), case);
+        String::from_utf8_unchecked(Vec::from(assume_init(buf)))
+    }
+}
+
+/// Decodes a hex string to bytes case-insensitively in parallel.
+///
+/// # Errors
+/// This function returns `Err` if the content of `data` is invalid.
+#[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+#[inline]
+pub fn par_decode_to_vec(data: impl AsRef<[u8]>) -> Result<Vec<u8>, Error> {
+    let src = data.as_ref();
+    if src.is_empty() {
+        return Ok(Vec::new());
+    }
+    let m = crate::decoded_length(src.len())?;
+    unsafe {
+        let mut buf = alloc_uninit_bytes(m);
+        par_decode(src, Out::from_uninit_slice(&mut buf))?;
+        Ok(Vec::from(assume_init(buf)))
+    }
+}
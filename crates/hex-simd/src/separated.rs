@@ -0,0 +1,205 @@
+use crate::encode::encode_fallback;
+use crate::Error;
+
+use vsimd::ascii::AsciiCase;
+use vsimd::hex::unhex;
+use vsimd::mask::mask8x16_all;
+use vsimd::tools::{read, write};
+use vsimd::vector::{V128, V256};
+use vsimd::SIMD256;
+
+/// Shuffles which spread the 32 characters of 16 encoded bytes into `xx?xx?...`,
+/// where `?` is the separator. Only the middle vector takes characters from both halves.
+const fn spread_tables() -> [[[u8; 16]; 3]; 3] {
+    let mut lo = [[0; 16]; 3];
+    let mut hi = [[0; 16]; 3];
+    let mut sep = [[0; 16]; 3];
+    let mut k = 0;
+    while k < 3 {
+        let mut j = 0;
+        while j < 16 {
+            let pos = k * 16 + j;
+            let (byte, rem) = (pos / 3, pos % 3);
+            let idx = 2 * byte + rem;
+            lo[k][j] = if rem < 2 && idx < 16 { idx as u8 } else { 0x80 };
+            hi[k][j] = if rem < 2 && idx >= 16 { (idx - 16) as u8 } else { 0x80 };
+            sep[k][j] = if rem == 2 { 0xff } else { 0 };
+            j += 1;
+        }
+        k += 1;
+    }
+    [lo, hi, sep]
+}
+
+const SPREAD: [[[u8; 16]; 3]; 3] = spread_tables();
+
+#[inline(always)]
+unsafe fn spread_bytes16<S: SIMD256>(s: S, src: *const u8, dst: *mut u8, lut: V256, sep: V128) {
+    let x = s.v128_load_unaligned(src);
+    let (lo, hi) = vsimd::hex::encode_bytes16(s, x, lut).to_v128x2();
+
+    let mut k = 0;
+    while k < 3 {
+        let y1 = s.u8x16_swizzle(lo, V128::from_bytes(SPREAD[0][k]));
+        let y2 = s.u8x16_swizzle(hi, V128::from_bytes(SPREAD[1][k]));
+        let y3 = s.v128_and(sep, V128::from_bytes(SPREAD[2][k]));
+        s.v128_store_unaligned(dst.add(k * 16), s.v128_or(s.v128_or(y1, y2), y3));
+        k += 1;
+    }
+}
+
+#[inline(always)]
+unsafe fn encode_separated_short(mut src: *const u8, len: usize, mut dst: *mut u8, sep: u8, case: AsciiCase) {
+    let end = src.add(len);
+    while src < end {
+        encode_fallback(src, 1, dst, case);
+        write(dst, 2, sep);
+        src = src.add(1);
+        dst = dst.add(3);
+    }
+}
+
+/// Writes `len * 3 - 1` bytes, which is `len` encoded bytes joined by `sep`.
+#[inline(always)]
+pub unsafe fn encode_separated_fallback(src: *const u8, len: usize, dst: *mut u8, sep: u8, case: AsciiCase) {
+    if len == 0 {
+        return;
+    }
+    encode_separated_short(src, len - 1, dst, sep, case);
+    encode_fallback(src.add(len - 1), 1, dst.add(len * 3 - 3), case);
+}
+
+#[inline(always)]
+pub unsafe fn encode_separated_simd<S: SIMD256>(
+    s: S,
+    mut src: *const u8,
+    mut len: usize,
+    mut dst: *mut u8,
+    sep: u8,
+    case: AsciiCase,
+) {
+    let lut = match case {
+        AsciiCase::Lower => vsimd::hex::ENCODE_LOWER_LUT,
+        AsciiCase::Upper => vsimd::hex::ENCODE_UPPER_LUT,
+    };
+    let sep_v = s.u8x16_splat(sep);
+
+    // the last separator of a block must not be written past the end
+    while len > 16 {
+        spread_bytes16(s, src, dst, lut, sep_v);
+        src = src.add(16);
+        dst = dst.add(48);
+        len -= 16;
+    }
+
+    if len == 16 {
+        let mut buf = [0u8; 48];
+        spread_bytes16(s, src, buf.as_mut_ptr(), lut, sep_v);
+        dst.copy_from_nonoverlapping(buf.as_ptr(), 47);
+        return;
+    }
+
+    encode_separated_fallback(src, len, dst, sep, case);
+}
+
+/// Shuffles which gather the hex characters of `xx?xx?...` into two vectors of 16 characters,
+/// and the masks of non-separator positions.
+const fn gather_tables() -> ([[u8; 16]; 4], [[u8; 16]; 3]) {
+    let mut shuffles = [[0x80; 16]; 4];
+    let mut masks = [[0xff; 16]; 3];
+    let mut idx = 0;
+    while idx < 32 {
+        let pos = idx / 2 * 3 + idx % 2;
+        let (k, lane) = (pos / 16, pos % 16);
+        // lo: chunks 0 and 1, hi: chunks 1 and 2
+        let t = if idx < 16 { k } else { k + 1 };
+        shuffles[t][idx % 16] = lane as u8;
+        idx += 1;
+    }
+    let mut pos = 2;
+    while pos < 48 {
+        masks[pos / 16][pos % 16] = 0;
+        pos += 3;
+    }
+    (shuffles, masks)
+}
+
+const GATHER: ([[u8; 16]; 4], [[u8; 16]; 3]) = gather_tables();
+
+#[inline(always)]
+unsafe fn gather_bytes16<S: SIMD256>(s: S, src: *const u8, dst: *mut u8, sep: V128) -> Result<(), Error> {
+    let x0 = s.v128_load_unaligned(src);
+    let x1 = s.v128_load_unaligned(src.add(16));
+    let x2 = s.v128_load_unaligned(src.add(32));
+
+    let mut is_sep = s.u8x16_splat(0xff);
+    for (x, mask) in [(x0, GATHER.1[0]), (x1, GATHER.1[1]), (x2, GATHER.1[2])] {
+        let eq = s.u8x16_eq(x, sep);
+        is_sep = s.v128_and(is_sep, s.v128_or(eq, V128::from_bytes(mask)));
+    }
+    ensure!(mask8x16_all(s, is_sep));
+
+    let lo = s.v128_or(
+        s.u8x16_swizzle(x0, V128::from_bytes(GATHER.0[0])),
+        s.u8x16_swizzle(x1, V128::from_bytes(GATHER.0[1])),
+    );
+    let hi = s.v128_or(
+        s.u8x16_swizzle(x1, V128::from_bytes(GATHER.0[2])),
+        s.u8x16_swizzle(x2, V128::from_bytes(GATHER.0[3])),
+    );
+    let y = try_!(vsimd::hex::decode_ascii32(s, V256::from_v128x2((lo, hi))));
+    s.v128_store_unaligned(dst, y);
+    Ok(())
+}
+
+/// Decodes `len` bytes of `len / 3 + 1` encoded bytes joined by `sep`.
+/// `len` must be zero or `len % 3 == 2`.
+#[inline(always)]
+pub unsafe fn decode_separated_fallback(
+    mut src: *const u8,
+    len: usize,
+    mut dst: *mut u8,
+    sep: u8,
+) -> Result<(), Error> {
+    if len == 0 {
+        return Ok(());
+    }
+    let n = len / 3 + 1;
+    let mut flag = 0;
+    let mut i = 0;
+    while i < n {
+        let y1 = unhex(read(src, 0));
+        let y2 = unhex(read(src, 1));
+        flag |= y1 | y2;
+        dst.write(y1.wrapping_shl(4) | y2);
+        if i + 1 < n {
+            ensure!(read(src, 2) == sep);
+        }
+        src = src.add(3);
+        dst = dst.add(1);
+        i += 1;
+    }
+    ensure!(flag != 0xff);
+    Ok(())
+}
+
+#[inline(always)]
+pub unsafe fn decode_separated_simd<S: SIMD256>(
+    s: S,
+    mut src: *const u8,
+    mut len: usize,
+    mut dst: *mut u8,
+    sep: u8,
+) -> Result<(), Error> {
+    let sep_v = s.u8x16_splat(sep);
+
+    // the last block has no trailing separator
+    while len > 48 {
+        gather_bytes16(s, src, dst, sep_v)?;
+        src = src.add(48);
+        dst = dst.add(16);
+        len -= 48;
+    }
+
+    decode_separated_fallback(src, len, dst, sep)
+}
@@ -0,0 +1,156 @@
+//! Serde helpers for hex string fields.
+//!
+//! Each module is used with `#[serde(with = "...")]` on a field of `Vec<u8>`, `Box<[u8]>` or `[u8; N]`,
+//! or any type implementing both `AsRef<[u8]>` and [`FromHexDecode`].
+//!
+//! + [`lower`] and [`upper`] serialize a hex string in the ascii case.
+//! + [`prefixed_lower`] and [`prefixed_upper`] serialize a hex string with a `0x` prefix.
+//!
+//! Deserialization is case-insensitive in all modules.
+//! The prefixed modules accept a hex string with or without a `0x` or `0X` prefix.
+//!
+//! # Examples
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Block {
+//!     #[serde(with = "hex_simd::serde::lower")]
+//!     hash: [u8; 4],
+//!     #[serde(with = "hex_simd::serde::prefixed_upper")]
+//!     data: Vec<u8>,
+//! }
+//!
+//! let block = Block { hash: [0xde, 0xad, 0xbe, 0xef], data: vec![0xab, 0xcd] };
+//! let json = serde_json::to_string(&block).unwrap();
+//! assert_eq!(json, r#"{"hash":"deadbeef","data":"0xABCD"}"#);
+//!
+//! let block: Block = serde_json::from_str(r#"{"hash":"DEADbeef","data":"abcd"}"#).unwrap();
+//! assert_eq!(block.hash, [0xde, 0xad, 0xbe, 0xef]);
+//! assert_eq!(block.data, [0xab, 0xcd]);
+//! ```
+
+use crate::{display, AsciiCase, FromHexDecode};
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use ::serde::de::{self, Deserializer, Visitor};
+use ::serde::Serializer;
+
+struct Hex<'a> {
+    data: &'a [u8],
+    case: AsciiCase,
+    prefix: bool,
+}
+
+impl fmt::Display for Hex<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.prefix {
+            f.write_str("0x")?;
+        }
+        match self.case {
+            AsciiCase::Lower => fmt::LowerHex::fmt(&display(self.data), f),
+            AsciiCase::Upper => fmt::UpperHex::fmt(&display(self.data), f),
+        }
+    }
+}
+
+#[inline(always)]
+fn serialize<S: Serializer>(data: &[u8], serializer: S, case: AsciiCase, prefix: bool) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&Hex { data, case, prefix })
+}
+
+struct HexVisitor<T> {
+    prefix: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<T: FromHexDecode> Visitor<'_> for HexVisitor<T> {
+    type Value = T;
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.prefix {
+            f.write_str("a hex string with an optional 0x prefix")
+        } else {
+            f.write_str("a hex string")
+        }
+    }
+
+    #[inline]
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        let src = match v {
+            [b'0', b'x' | b'X', rest @ ..] if self.prefix => rest,
+            _ => v,
+        };
+        T::from_hex_decode(src).map_err(E::custom)
+    }
+
+    #[inline]
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        self.visit_bytes(v.as_bytes())
+    }
+}
+
+#[inline(always)]
+fn deserialize<'de, D: Deserializer<'de>, T: FromHexDecode>(deserializer: D, prefix: bool) -> Result<T, D::Error> {
+    deserializer.deserialize_str(HexVisitor {
+        prefix,
+        _marker: PhantomData,
+    })
+}
+
+macro_rules! define_module {
+    ($name:ident, $case:expr, $prefix:expr, $doc:literal) => {
+        #[doc = $doc]
+        pub mod $name {
+            use crate::{AsciiCase, FromHexDecode};
+
+            use ::serde::{Deserializer, Serializer};
+
+            /// Serializes bytes to a hex string.
+            ///
+            /// # Errors
+            /// This function returns `Err` if the serializer fails.
+            #[inline]
+            pub fn serialize<S, T>(data: &T, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+                T: AsRef<[u8]> + ?Sized,
+            {
+                super::serialize(data.as_ref(), serializer, $case, $prefix)
+            }
+
+            /// Deserializes bytes from a hex string case-insensitively.
+            ///
+            /// # Errors
+            /// This function returns `Err` if the deserializer fails or the hex string is invalid.
+            #[inline]
+            pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+            where
+                D: Deserializer<'de>,
+                T: FromHexDecode,
+            {
+                super::deserialize(deserializer, $prefix)
+            }
+        }
+    };
+}
+
+define_module!(lower, AsciiCase::Lower, false, "Lowercase hex strings.");
+define_module!(upper, AsciiCase::Upper, false, "Uppercase hex strings.");
+define_module!(
+    prefixed_lower,
+    AsciiCase::Lower,
+    true,
+    "Lowercase hex strings with a `0x` prefix."
+);
+define_module!(
+    prefixed_upper,
+    AsciiCase::Upper,
+    true,
+    "Uppercase hex strings with a `0x` prefix."
+);
@@ -0,0 +1,264 @@
+//! The Motorola S-record file format.
+//!
+//! Each record is a line of `S<type><count><address><data><checksum>`,
+//! where the address has 16, 24 or 32 bits depending on the record type.
+//!
+//! # Examples
+//!
+//! ```
+//! use hex_simd::srec::{self, AddressWidth, SRecordFile, Segment};
+//!
+//! let text = "\
+//!     S00600004844521B\n\
+//!     S1060100AABBCCC7\n\
+//!     S5030001FB\n\
+//!     S9030100FB\n";
+//!
+//! let file = srec::parse(text.as_bytes()).unwrap();
+//! assert_eq!(file.header, b"HDR");
+//! assert_eq!(file.segments, [Segment { address: 0x0100, data: vec![0xaa, 0xbb, 0xcc] }]);
+//! assert_eq!(file.address_width, AddressWidth::Bits16);
+//! assert_eq!(file.start_address, Some(0x0100));
+//!
+//! assert_eq!(srec::write_to_string(&file, 16), text);
+//! ```
+
+use crate::error::offset_in;
+use crate::ihex::push_data;
+use crate::{AsciiCase, Error, ErrorKind};
+
+pub use crate::ihex::Segment;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// The width of addresses in data and termination records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum AddressWidth {
+    /// 16-bit addresses: `S1` data and `S9` termination records (S19).
+    #[default]
+    Bits16,
+    /// 24-bit addresses: `S2` data and `S8` termination records (S28).
+    Bits24,
+    /// 32-bit addresses: `S3` data and `S7` termination records (S37).
+    Bits32,
+}
+
+impl AddressWidth {
+    const fn address_len(self) -> usize {
+        match self {
+            AddressWidth::Bits16 => 2,
+            AddressWidth::Bits24 => 3,
+            AddressWidth::Bits32 => 4,
+        }
+    }
+
+    const fn max_address(self) -> u32 {
+        match self {
+            AddressWidth::Bits16 => 0xffff,
+            AddressWidth::Bits24 => 0x00ff_ffff,
+            AddressWidth::Bits32 => 0xffff_ffff,
+        }
+    }
+}
+
+/// The content of an S-record file.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SRecordFile {
+    /// The data of the `S0` header record.
+    pub header: Vec<u8>,
+    /// The data segments in the order of the file.
+    ///
+    /// Consecutive data records at adjacent addresses are merged into one segment.
+    pub segments: Vec<Segment>,
+    /// The widest address of the data records, which selects the record types to write.
+    pub address_width: AddressWidth,
+    /// The address of the termination record.
+    pub start_address: Option<u32>,
+}
+
+#[inline(always)]
+fn checksum(bytes: &[u8]) -> u8 {
+    !bytes.iter().fold(0u8, |acc, &x| acc.wrapping_add(x))
+}
+
+/// Decodes the bytes of a record line and checks its length and checksum.
+/// Returns the record type and the bytes from the count to the checksum.
+/// The offsets of errors are relative to `line`.
+fn parse_record<'b>(line: &[u8], buf: &'b mut Vec<u8>) -> Result<(u8, &'b [u8]), Error> {
+    ensure!(line.len() >= 2, InvalidLength);
+    if line[0] != b'S' {
+        let byte = line[0];
+        return Err(Error::from_kind(ErrorKind::InvalidCharacter { offset: 0, byte }));
+    }
+    if !line[1].is_ascii_digit() {
+        let byte = line[1];
+        return Err(Error::from_kind(ErrorKind::InvalidCharacter { offset: 1, byte }));
+    }
+
+    buf.clear();
+    crate::decode_append(&line[2..], buf).map_err(|e| e.add_offset(2))?;
+    ensure!(!buf.is_empty() && buf.len() == usize::from(buf[0]) + 1, InvalidLength);
+
+    let (cc, bytes) = buf.split_last().unwrap();
+    if checksum(bytes) != *cc {
+        return Err(Error::from_kind(ErrorKind::InvalidChecksum { offset: 0 }));
+    }
+    Ok((line[1] - b'0', buf))
+}
+
+/// Parses an S-record file.
+///
+/// Empty lines are ignored and `\r\n` line endings are accepted.
+/// Records after the termination record are ignored.
+/// The record payloads are decoded case-insensitively.
+///
+/// # Errors
+/// This function returns `Err` if any record is malformed or has a wrong checksum,
+/// or if a count record does not match the number of data records.
+/// The offset of the error is relative to `text`.
+#[inline]
+pub fn parse(text: &[u8]) -> Result<SRecordFile, Error> {
+    let mut ans = SRecordFile::default();
+    let mut data_records: u32 = 0;
+    let mut buf = Vec::new();
+
+    for line in text.split(|&x| x == b'\n') {
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        if line.is_empty() {
+            continue;
+        }
+
+        let line_offset = offset_in(text, line);
+        let (ty, record) = parse_record(line, &mut buf).map_err(|e| e.add_offset(line_offset))?;
+        let invalid_record = || Error::from_kind(ErrorKind::InvalidRecord { offset: line_offset });
+
+        let width = match ty {
+            0 | 1 | 5 | 9 => AddressWidth::Bits16,
+            2 | 6 | 8 => AddressWidth::Bits24,
+            3 | 7 => AddressWidth::Bits32,
+            _ => return Err(invalid_record()),
+        };
+        let address_len = width.address_len();
+        if record.len() < 2 + address_len {
+            return Err(invalid_record());
+        }
+
+        let address = record[1..=address_len]
+            .iter()
+            .fold(0u32, |acc, &x| acc << 8 | u32::from(x));
+        let data = &record[1 + address_len..record.len() - 1];
+
+        match ty {
+            0 => ans.header = data.to_vec(),
+            1..=3 => {
+                push_data(&mut ans.segments, address, data);
+                ans.address_width = ans.address_width.max(width);
+                data_records = data_records.wrapping_add(1);
+            }
+            5 | 6 => {
+                if address != data_records {
+                    return Err(invalid_record());
+                }
+            }
+            _ => {
+                ans.start_address = Some(address);
+                break;
+            }
+        }
+    }
+
+    Ok(ans)
+}
+
+fn write_record<W: fmt::Write>(
+    f: &mut W,
+    line: &mut Vec<u8>,
+    ty: u8,
+    width: AddressWidth,
+    address: u32,
+    data: &[u8],
+) -> fmt::Result {
+    let mut bytes = [0u8; 1 + 4 + 255];
+    let address_len = width.address_len();
+    let n = 1 + address_len + data.len();
+    bytes[0] = n as u8;
+    bytes[1..=address_len].copy_from_slice(&address.to_be_bytes()[4 - address_len..]);
+    bytes[1 + address_len..n].copy_from_slice(data);
+    bytes[n] = checksum(&bytes[..n]);
+
+    line.clear();
+    line.extend_from_slice(&[b'S', b'0' + ty]);
+    crate::encode_append(&bytes[..=n], line, AsciiCase::Upper);
+    line.push(b'\n');
+    f.write_str(unsafe { core::str::from_utf8_unchecked(line) })
+}
+
+/// Writes an S-record file to `f`.
+///
+/// The file starts with an `S0` header record and ends with a count record (`S5` or `S6`)
+/// and a termination record. Data records contain at most `record_len` bytes
+/// and use the record types of [`SRecordFile::address_width`].
+/// The hex characters are uppercase and each record ends with `\n`.
+///
+/// # Errors
+/// This function returns `Err` if `f` fails to write.
+///
+/// # Panics
+/// This function panics if
+/// + `record_len` is zero.
+/// + the header is longer than 252 bytes.
+/// + any data byte or the start address does not fit in the address width.
+#[inline]
+pub fn write_to<W: fmt::Write>(file: &SRecordFile, record_len: u8, f: &mut W) -> fmt::Result {
+    assert!(record_len > 0);
+    assert!(file.header.len() <= 252);
+
+    let width = file.address_width;
+    let (data_ty, term_ty) = match width {
+        AddressWidth::Bits16 => (1, 9),
+        AddressWidth::Bits24 => (2, 8),
+        AddressWidth::Bits32 => (3, 7),
+    };
+    let max_len = usize::from(record_len).min(254 - width.address_len());
+
+    let mut line = Vec::new();
+    write_record(f, &mut line, 0, AddressWidth::Bits16, 0, &file.header)?;
+
+    let mut data_records: u32 = 0;
+    for seg in &file.segments {
+        let mut address = seg.address;
+        for chunk in seg.data.chunks(max_len) {
+            let last = u64::from(address) + chunk.len() as u64 - 1;
+            assert!(last <= u64::from(width.max_address()));
+            write_record(f, &mut line, data_ty, width, address, chunk)?;
+            address = address.wrapping_add(chunk.len() as u32);
+            data_records = data_records.wrapping_add(1);
+        }
+    }
+
+    if data_records <= AddressWidth::Bits16.max_address() {
+        write_record(f, &mut line, 5, AddressWidth::Bits16, data_records, &[])?;
+    } else if data_records <= AddressWidth::Bits24.max_address() {
+        write_record(f, &mut line, 6, AddressWidth::Bits24, data_records, &[])?;
+    }
+
+    let start = file.start_address.unwrap_or(0);
+    assert!(start <= width.max_address());
+    write_record(f, &mut line, term_ty, width, start, &[])
+}
+
+/// Returns an S-record file.
+///
+/// See [`write_to`] for the format.
+///
+/// # Panics
+/// See [`write_to`].
+#[inline]
+#[must_use]
+pub fn write_to_string(file: &SRecordFile, record_len: u8) -> String {
+    let mut ans = String::new();
+    let _ = write_to(file, record_len, &mut ans);
+    ans
+}
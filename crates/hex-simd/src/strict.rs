@@ -0,0 +1,140 @@
+use crate::error::{self, Error};
+
+use vsimd::ascii::AsciiCase;
+use vsimd::isa::AVX2;
+use vsimd::tools::read;
+use vsimd::vector::V64;
+use vsimd::{matches_isa, SIMD256};
+
+const fn unhex_table(case: AsciiCase) -> [u8; 256] {
+    let letter = match case {
+        AsciiCase::Lower => b'a',
+        AsciiCase::Upper => b'A',
+    };
+    let mut arr = [0xff; 256];
+    let mut i = 0;
+    while i < 10 {
+        arr[(b'0' + i) as usize] = i;
+        i += 1;
+    }
+    let mut i = 0;
+    while i < 6 {
+        arr[(letter + i) as usize] = 10 + i;
+        i += 1;
+    }
+    arr
+}
+
+const LOWER_UNHEX_TABLE: &[u8; 256] = &unhex_table(AsciiCase::Lower);
+const UPPER_UNHEX_TABLE: &[u8; 256] = &unhex_table(AsciiCase::Upper);
+
+#[inline(always)]
+fn unhex_table_of(case: AsciiCase) -> &'static [u8; 256] {
+    match case {
+        AsciiCase::Lower => LOWER_UNHEX_TABLE,
+        AsciiCase::Upper => UPPER_UNHEX_TABLE,
+    }
+}
+
+/// Finds the first character which is not a hex character of `case`.
+#[cold]
+#[inline(never)]
+pub(crate) fn locate(src: &[u8], case: AsciiCase) -> Error {
+    let table = unhex_table_of(case);
+    error::locate(src, |_, x| table[x as usize] != 0xff)
+}
+
+#[inline(always)]
+pub unsafe fn check_strict_fallback(mut src: *const u8, len: usize, case: AsciiCase) -> Result<(), Error> {
+    let table = unhex_table_of(case);
+    let mut flag = 0;
+    let end = src.add(len);
+    while src < end {
+        flag |= read(table.as_ptr(), src.read() as usize);
+        src = src.add(1);
+    }
+    ensure!(flag != 0xff);
+    Ok(())
+}
+
+#[inline(always)]
+pub unsafe fn check_strict_simd<S: SIMD256>(
+    s: S,
+    mut src: *const u8,
+    mut len: usize,
+    case: AsciiCase,
+) -> Result<(), Error> {
+    if matches_isa!(S, AVX2) {
+        let end = src.add(len / 32 * 32);
+        while src < end {
+            let x = s.v256_load_unaligned(src);
+            ensure!(vsimd::hex::check_case_xn(s, x, case));
+            src = src.add(32);
+        }
+        len %= 32;
+    }
+
+    let end = src.add(len / 16 * 16);
+    while src < end {
+        let x = s.v128_load_unaligned(src);
+        ensure!(vsimd::hex::check_case_xn(s, x, case));
+        src = src.add(16);
+    }
+    len %= 16;
+
+    check_strict_fallback(src, len, case)
+}
+
+#[inline(always)]
+pub unsafe fn decode_strict_fallback(
+    mut src: *const u8,
+    len: usize,
+    mut dst: *mut u8,
+    case: AsciiCase,
+) -> Result<(), Error> {
+    let table = unhex_table_of(case).as_ptr();
+    let mut flag = 0;
+    let end = src.add(len);
+    while src < end {
+        let y1 = read(table, read(src, 0) as usize);
+        let y2 = read(table, read(src, 1) as usize);
+        flag |= y1 | y2;
+        dst.write(y1.wrapping_shl(4) | y2);
+        src = src.add(2);
+        dst = dst.add(1);
+    }
+    ensure!(flag != 0xff);
+    Ok(())
+}
+
+#[inline(always)]
+pub unsafe fn decode_strict_simd<S: SIMD256>(
+    s: S,
+    mut src: *const u8,
+    mut len: usize,
+    mut dst: *mut u8,
+    case: AsciiCase,
+) -> Result<(), Error> {
+    let end = src.add(len / 32 * 32);
+    while src < end {
+        let x = s.v256_load_unaligned(src);
+        ensure!(vsimd::hex::check_case_xn(s, x, case));
+        let y = try_!(vsimd::hex::decode_ascii32(s, x));
+        s.v128_store_unaligned(dst, y);
+        src = src.add(32);
+        dst = dst.add(16);
+    }
+    len %= 32;
+
+    if len >= 16 {
+        let x = s.v128_load_unaligned(src);
+        ensure!(vsimd::hex::check_case_xn(s, x, case));
+        let y = try_!(vsimd::hex::decode_ascii16(s, x));
+        dst.cast::<V64>().write_unaligned(y);
+        src = src.add(16);
+        dst = dst.add(8);
+        len -= 16;
+    }
+
+    decode_strict_fallback(src, len, dst, case)
+}
@@ -0,0 +1,271 @@
+use crate::decode::decode_fallback;
+use crate::encode::encode_fallback;
+use crate::error::locate_hex;
+use crate::{AsciiCase, Error, Out};
+
+use vsimd::tools::slice_mut;
+use vsimd::vector::{V128, V256};
+use vsimd::SIMD256;
+
+use core::mem::{size_of, size_of_val};
+
+/// The byte order of each word in a hex string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endian {
+    /// The most significant byte first, as in `{:x}`.
+    Big,
+    /// The least significant byte first.
+    Little,
+}
+
+impl Endian {
+    /// The byte order of the target.
+    pub const NATIVE: Self = if cfg!(target_endian = "little") {
+        Endian::Little
+    } else {
+        Endian::Big
+    };
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Unsigned integers which can be encoded by [`encode_words`] and decoded by [`decode_words`].
+///
+/// This trait is sealed and implemented for `u16`, `u32` and `u64`.
+pub trait Word: Copy + sealed::Sealed {}
+
+macro_rules! impl_word {
+    ($($ty:ty),*) => {$(
+        impl sealed::Sealed for $ty {}
+        impl Word for $ty {}
+    )*};
+}
+
+impl_word!(u16, u32, u64);
+
+/// Shuffles which reverse the bytes of each word of 2, 4 and 8 bytes.
+const fn swap_table(width: usize) -> V128 {
+    let mut ans = [0; 16];
+    let mut i = 0;
+    while i < 16 {
+        ans[i] = (i / width * width + (width - 1 - i % width)) as u8;
+        i += 1;
+    }
+    V128::from_bytes(ans)
+}
+
+/// Shuffles which reverse the bytes of each word and zero-extend them to `u16x16`.
+///
+/// The input is duplicated in both lanes, so that one shuffle does the swap and the interleave of the encoding.
+const fn swap_widen_table(width: usize) -> V256 {
+    let mut ans = [0x80; 32];
+    let mut i = 0;
+    while i < 16 {
+        ans[i * 2] = (i / width * width + (width - 1 - i % width)) as u8;
+        i += 1;
+    }
+    V256::from_bytes(ans)
+}
+
+const SWAP: [V128; 4] = [swap_table(1), swap_table(2), swap_table(4), swap_table(8)];
+
+const SWAP_WIDEN: [V256; 4] = [
+    swap_widen_table(1),
+    swap_widen_table(2),
+    swap_widen_table(4),
+    swap_widen_table(8),
+];
+
+#[inline(always)]
+fn swap_shuffle(width: usize) -> V128 {
+    SWAP[width.trailing_zeros() as usize]
+}
+
+#[inline(always)]
+fn swap_widen_shuffle(width: usize) -> V256 {
+    SWAP_WIDEN[width.trailing_zeros() as usize]
+}
+
+/// Encodes `len` bytes, reversing the bytes of each word of `width` bytes.
+#[inline(always)]
+pub(crate) unsafe fn encode_swapped_fallback(
+    mut src: *const u8,
+    len: usize,
+    mut dst: *mut u8,
+    width: usize,
+    case: AsciiCase,
+) {
+    let end = src.add(len);
+    let mut word = [0u8; 8];
+    while src < end {
+        let mut i = 0;
+        while i < width {
+            word[i] = src.add(width - 1 - i).read();
+            i += 1;
+        }
+        encode_fallback(word.as_ptr(), width, dst, case);
+        src = src.add(width);
+        dst = dst.add(width * 2);
+    }
+}
+
+#[inline(always)]
+pub(crate) unsafe fn encode_swapped_simd<S: SIMD256>(
+    s: S,
+    mut src: *const u8,
+    len: usize,
+    mut dst: *mut u8,
+    width: usize,
+    case: AsciiCase,
+) {
+    let lut = match case {
+        AsciiCase::Lower => vsimd::hex::ENCODE_LOWER_LUT,
+        AsciiCase::Upper => vsimd::hex::ENCODE_UPPER_LUT,
+    };
+    let shuffle = swap_widen_shuffle(width);
+
+    let end = src.add(len / 16 * 16);
+    while src < end {
+        let x = s.v128_load_unaligned(src);
+        let x = s.u8x16x2_swizzle(V256::from_v128x2((x, x)), shuffle);
+        let y = vsimd::hex::encode_u16x16(s, x, lut);
+        s.v256_store_unaligned(dst, y);
+        src = src.add(16);
+        dst = dst.add(32);
+    }
+
+    encode_swapped_fallback(src, len % 16, dst, width, case);
+}
+
+/// Decodes `len` hex characters, reversing the bytes of each word of `width` bytes.
+#[inline(always)]
+pub(crate) unsafe fn decode_swapped_fallback(
+    mut src: *const u8,
+    len: usize,
+    mut dst: *mut u8,
+    width: usize,
+) -> Result<(), Error> {
+    let end = src.add(len);
+    let mut word = [0u8; 8];
+    while src < end {
+        decode_fallback(src, width * 2, word.as_mut_ptr())?;
+        let mut i = 0;
+        while i < width {
+            dst.add(i).write(word[width - 1 - i]);
+            i += 1;
+        }
+        src = src.add(width * 2);
+        dst = dst.add(width);
+    }
+    Ok(())
+}
+
+#[inline(always)]
+pub(crate) unsafe fn decode_swapped_simd<S: SIMD256>(
+    s: S,
+    mut src: *const u8,
+    len: usize,
+    mut dst: *mut u8,
+    width: usize,
+) -> Result<(), Error> {
+    let shuffle = swap_shuffle(width);
+
+    let end = src.add(len / 32 * 32);
+    while src < end {
+        let x = s.v256_load_unaligned(src);
+        let y = try_!(vsimd::hex::decode_ascii32(s, x));
+        s.v128_store_unaligned(dst, s.u8x16_swizzle(y, shuffle));
+        src = src.add(32);
+        dst = dst.add(16);
+    }
+
+    decode_swapped_fallback(src, len % 32, dst, width)
+}
+
+/// Encodes words to a hex string.
+///
+/// Each word is written as `2 * size_of::<T>()` hex characters in the byte order of `endian`,
+/// regardless of the byte order of the target.
+/// [`Endian::Big`] gives the human-readable form, as in `{:x}` with leading zeros.
+///
+/// `case` specifies the ascii case of output.
+///
+/// # Examples
+///
+/// ```
+/// use hex_simd::{AsOut, AsciiCase, Endian};
+///
+/// let words = [0xdead_beef_u32, 1];
+/// let mut buf = [0u8; 16];
+///
+/// let ans = hex_simd::encode_words(&words, buf.as_mut_slice().as_out(), AsciiCase::Lower, Endian::Big);
+/// assert_eq!(ans.unwrap(), b"deadbeef00000001");
+///
+/// let ans = hex_simd::encode_words(&words, buf.as_mut_slice().as_out(), AsciiCase::Lower, Endian::Little);
+/// assert_eq!(ans.unwrap(), b"efbeadde01000000");
+/// ```
+///
+/// # Errors
+/// This function returns `Err` if the length of `dst` is not enough.
+#[inline]
+pub fn encode_words<'d, T: Word>(
+    src: &[T],
+    mut dst: Out<'d, [u8]>,
+    case: AsciiCase,
+    endian: Endian,
+) -> Result<&'d mut [u8], Error> {
+    let width = size_of::<T>();
+    let len = size_of_val(src);
+    ensure!(dst.len() / 2 >= len, BufferTooSmall);
+    unsafe {
+        let src: *const u8 = src.as_ptr().cast();
+        let dst = dst.as_mut_ptr();
+        if endian == Endian::NATIVE {
+            crate::multiversion::encode::auto(src, len, dst, case);
+        } else {
+            crate::multiversion::encode_swapped::auto(src, len, dst, width, case);
+        }
+        Ok(slice_mut(dst, len * 2))
+    }
+}
+
+/// Decodes a hex string to words case-insensitively.
+///
+/// Each word is read from `2 * size_of::<T>()` hex characters in the byte order of `endian`,
+/// regardless of the byte order of the target.
+///
+/// # Examples
+///
+/// ```
+/// use hex_simd::{AsOut, Endian};
+///
+/// let mut buf = [0u16; 2];
+/// let ans = hex_simd::decode_words(b"BEEF0001", buf.as_mut_slice().as_out(), Endian::Big);
+/// assert_eq!(ans.unwrap(), [0xbeef, 1]);
+/// ```
+///
+/// # Errors
+/// This function returns `Err` if
+/// + the length of `src` is not a multiple of `2 * size_of::<T>()`.
+/// + the length of `dst` is not enough.
+/// + the content of `src` is invalid.
+#[inline]
+pub fn decode_words<'d, T: Word>(src: &[u8], mut dst: Out<'d, [T]>, endian: Endian) -> Result<&'d mut [T], Error> {
+    let width = size_of::<T>();
+    ensure!(src.len() % (width * 2) == 0, InvalidLength);
+    let n = src.len() / (width * 2);
+    ensure!(dst.len() >= n, BufferTooSmall);
+    unsafe {
+        let len = src.len();
+        let dst = dst.as_mut_ptr();
+        let ans = if endian == Endian::NATIVE {
+            crate::multiversion::decode::auto(src.as_ptr(), len, dst.cast())
+        } else {
+            crate::multiversion::decode_swapped::auto(src.as_ptr(), len, dst.cast(), width)
+        };
+        ans.map_err(|_| locate_hex(src))?;
+        Ok(slice_mut(dst, n))
+    }
+}
@@ -152,3 +152,837 @@ fn random() {
         test_encode_decode_inplace!(src, AsciiCase::Upper);
     }
 }
+
+#[cfg(feature = "std")]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn io() {
+    use hex_simd::io::{DecoderReader, EncoderWriter};
+    use std::io::{ErrorKind, Read, Write};
+
+    struct OneByte<'a>(&'a [u8]);
+
+    impl Read for OneByte<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let n = self.0.len().min(buf.len()).min(1);
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+            Ok(n)
+        }
+    }
+
+    for n in [0, 1, 15, 16, 17, 100, 4096, 4097, 20000] {
+        let src = rand_bytes(n);
+
+        for case in [AsciiCase::Lower, AsciiCase::Upper] {
+            let mut writer = EncoderWriter::new(Vec::new(), case);
+            for chunk in src.chunks(777) {
+                writer.write_all(chunk).unwrap();
+            }
+            let encoded = writer.into_inner().unwrap();
+            assert_eq!(encoded, hex_simd::encode_type::<Vec<u8>>(&src, case));
+
+            let mut decoded = Vec::new();
+            DecoderReader::new(encoded.as_slice())
+                .read_to_end(&mut decoded)
+                .unwrap();
+            assert_eq!(decoded, src);
+
+            if n <= 100 {
+                let mut decoded = Vec::new();
+                DecoderReader::new(OneByte(&encoded)).read_to_end(&mut decoded).unwrap();
+                assert_eq!(decoded, src);
+            }
+        }
+    }
+
+    {
+        let mut buf = Vec::new();
+        let err = DecoderReader::new(&b"abc"[..]).read_to_end(&mut buf).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+        assert_eq!(buf, [0xab]);
+
+        let err = DecoderReader::new(&b"abxy"[..])
+            .read_to_end(&mut Vec::new())
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn dump() {
+    use hex_simd::dump::{dump_to_string, undump, DumpFormat};
+    use hex_simd::ErrorKind;
+
+    let mut data = b"Hello world!\n".to_vec();
+    data.extend_from_slice(&[0; 51]);
+    data.extend_from_slice(b"\x7f\x80\xff~ ");
+
+    let hexdump = "\
+        00000000  48 65 6c 6c 6f 20 77 6f  72 6c 64 21 0a 00 00 00  |Hello world!....|\n\
+        00000010  00 00 00 00 00 00 00 00  00 00 00 00 00 00 00 00  |................|\n\
+        *\n\
+        00000040  7f 80 ff 7e 20                                    |...~ |\n\
+        00000045\n";
+    assert_eq!(dump_to_string(&data, DumpFormat::HEXDUMP), hexdump);
+    assert_eq!(undump(hexdump.as_bytes()).unwrap(), data);
+
+    let xxd = "\
+        00000000: 48656C6C6F20 776F726C6421 0A0000000000 000000000000  Hello world!............\n\
+        00000018: 000000000000 000000000000 000000000000 000000000000  ........................\n\
+        00000030: 000000000000 000000000000 000000007F80 FF7E20        ...................~ \n";
+    let format = DumpFormat::XXD.width(24).group(6).case(AsciiCase::Upper);
+    assert_eq!(dump_to_string(&data, format), xxd);
+    assert_eq!(undump(xxd.as_bytes()).unwrap(), data);
+
+    assert!(dump_to_string(&[], DumpFormat::HEXDUMP).is_empty());
+    assert!(undump(b"").unwrap().is_empty());
+    assert_eq!(undump(b"00000004: 4142  AB\n").unwrap(), b"\0\0\0\0AB");
+    assert!(undump(b"00000000: 414  A\n").is_err());
+    assert!(undump(b"hello\n").is_err());
+
+    let err = undump(b"ffffffffffffffff: 0102\n").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidRecord { offset: 0 });
+    let err = undump(b"00000000: 0102\n7fffffffffffffff: 0102\n").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidRecord { offset: 15 });
+    let err = undump(b"00000000: 0102\n*\n7fffffffffffffff: 0102\n").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidRecord { offset: 17 });
+    let err = undump(b"ffffff:00\n").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidRecord { offset: 0 });
+    let err = undump(b"00000000: 0102\nffffffff: 00\n").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidRecord { offset: 15 });
+    assert_eq!(undump(b"00100000: 00\n").unwrap().len(), (1 << 20) + 1);
+    let text = dump_to_string(&vec![0; 1 << 22], DumpFormat::HEXDUMP);
+    assert_eq!(undump(text.as_bytes()).unwrap(), vec![0; 1 << 22]);
+
+    for n in [0, 1, 16, 17, 100, 1000] {
+        let data = rand_bytes(n);
+        for width in [1, 7, 16, 17, 32, 40] {
+            for format in [DumpFormat::HEXDUMP, DumpFormat::XXD] {
+                let format = format.width(width);
+                let text = dump_to_string(&data, format);
+                assert_eq!(undump(text.as_bytes()).unwrap(), data);
+
+                let first = text.lines().next().unwrap_or_default();
+                let expected: String = data.iter().take(width).map(|x| format!("{x:02x}")).collect();
+                let actual: String = first.split_whitespace().skip(1).collect();
+                assert!(actual.starts_with(&expected));
+            }
+        }
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn separated() {
+    let mut buf = [MaybeUninit::<u8>::uninit(); 8];
+    let ans = hex_simd::encode_separated(&[0xaa, 0xbb, 0xcc], buf.as_mut_slice().as_out(), b':', AsciiCase::Upper);
+    assert_eq!(ans.unwrap(), b"AA:BB:CC");
+
+    let mut buf = [MaybeUninit::<u8>::uninit(); 3];
+    let ans = hex_simd::decode_separated(b"aa-Bb-cC", buf.as_mut_slice().as_out(), b'-');
+    assert_eq!(ans.unwrap(), [0xaa, 0xbb, 0xcc]);
+
+    let mut buf = [MaybeUninit::<u8>::uninit(); 3];
+    assert!(hex_simd::decode_separated(b"aa:bb:cc", buf.as_mut_slice().as_out(), b'-').is_err());
+    assert!(hex_simd::decode_separated(b"aa:bb:c", buf.as_mut_slice().as_out(), b':').is_err());
+    assert!(hex_simd::decode_separated(b"aa:bbcc", buf.as_mut_slice().as_out(), b':').is_err());
+    assert!(hex_simd::decode_separated(b"aa:bb:cc:dd", buf.as_mut_slice().as_out(), b':').is_err());
+    assert!(hex_simd::encode_separated(&[1, 2], buf.as_mut_slice().as_out(), b':', AsciiCase::Lower).is_err());
+
+    for n in 0..100 {
+        let src = rand_bytes(n);
+        for (sep, case) in [(b':', AsciiCase::Lower), (b' ', AsciiCase::Upper)] {
+            let expected: Vec<String> = src
+                .iter()
+                .map(|x| match case {
+                    AsciiCase::Lower => format!("{x:02x}"),
+                    AsciiCase::Upper => format!("{x:02X}"),
+                })
+                .collect();
+            let expected = expected.join(&(sep as char).to_string());
+
+            let mut encoded = vec![MaybeUninit::<u8>::uninit(); n * 3];
+            let encoded = hex_simd::encode_separated(&src, encoded.as_mut_slice().as_out(), sep, case).unwrap();
+            assert_eq!(encoded, expected.as_bytes());
+
+            let mut decoded = vec![MaybeUninit::<u8>::uninit(); n];
+            let decoded = hex_simd::decode_separated(encoded, decoded.as_mut_slice().as_out(), sep).unwrap();
+            assert_eq!(decoded, src);
+
+            for i in 0..encoded.len() {
+                let mut bad = encoded.to_vec();
+                bad[i] = if i % 3 == 2 { b'.' } else { b'g' };
+                let mut decoded = vec![MaybeUninit::<u8>::uninit(); n];
+                assert!(hex_simd::decode_separated(&bad, decoded.as_mut_slice().as_out(), sep).is_err());
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn decode_with() {
+    use hex_simd::{DecodeOptions, OddLength, Prefix};
+
+    let all = DecodeOptions {
+        prefix: Prefix::Allow0x,
+        odd: OddLength::LeadingZeroNibble,
+        ignore_whitespace: true,
+    };
+    let require = DecodeOptions {
+        prefix: Prefix::Require0x,
+        ..DecodeOptions::default()
+    };
+
+    let cases: &[(&str, DecodeOptions, Option<&[u8]>)] = &[
+        ("", DecodeOptions::default(), Some(&[])),
+        ("abcd", DecodeOptions::default(), Some(&[0xab, 0xcd])),
+        ("0xabcd", DecodeOptions::default(), None),
+        ("abc", DecodeOptions::default(), None),
+        ("ab cd", DecodeOptions::default(), None),
+        ("0xabcd", require, Some(&[0xab, 0xcd])),
+        ("0XAbcd", require, Some(&[0xab, 0xcd])),
+        ("0x", require, Some(&[])),
+        ("abcd", require, None),
+        ("0xabc", require, None),
+        ("abcd", all, Some(&[0xab, 0xcd])),
+        ("0xabcd", all, Some(&[0xab, 0xcd])),
+        ("0x0xab", all, None),
+        ("abc", all, Some(&[0x0a, 0xbc])),
+        ("0x1", all, Some(&[0x01])),
+        (" \t0x a\nb\r\nc d e ", all, Some(&[0x0a, 0xbc, 0xde])),
+        ("a b", all, Some(&[0xab])),
+        ("a g", all, None),
+        ("g", all, None),
+        ("0 x12", all, None),
+    ];
+
+    for &(src, options, expected) in cases {
+        let mut buf = [MaybeUninit::<u8>::uninit(); 8];
+        let ans = hex_simd::decode_with(src.as_bytes(), buf.as_mut_slice().as_out(), options);
+        assert_eq!(ans.ok().map(|x| &*x), expected, "src = {src:?}");
+    }
+
+    for n in 0..200 {
+        let bytes = rand_bytes(n);
+        let hex = hex_simd::encode_type::<String>(&bytes, AsciiCase::Lower);
+        let wrapped: Vec<u8> = hex.as_bytes().chunks(63).flat_map(|c| [c, b"\n"].concat()).collect();
+        let src = [b"0x", wrapped.as_slice()].concat();
+
+        let mut buf = vec![MaybeUninit::<u8>::uninit(); n];
+        let ans = hex_simd::decode_with(&src, buf.as_mut_slice().as_out(), all).unwrap();
+        assert_eq!(ans, bytes);
+
+        if n > 0 {
+            let mut buf = vec![MaybeUninit::<u8>::uninit(); n];
+            let ans = hex_simd::decode_with(&src[3..], buf.as_mut_slice().as_out(), all).unwrap();
+            assert_eq!(ans[0], bytes[0] & 0x0f);
+            assert_eq!(ans[1..], bytes[1..]);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn strict() {
+    for n in 0..100 {
+        let bytes = rand_bytes(n);
+        for case in [AsciiCase::Lower, AsciiCase::Upper] {
+            let other = match case {
+                AsciiCase::Lower => AsciiCase::Upper,
+                AsciiCase::Upper => AsciiCase::Lower,
+            };
+            let hex: Vec<u8> = hex_simd::encode_type(&bytes, case);
+
+            hex_simd::check_strict(&hex, case).unwrap();
+            let mut buf = vec![MaybeUninit::<u8>::uninit(); n];
+            let ans = hex_simd::decode_strict(&hex, buf.as_mut_slice().as_out(), case).unwrap();
+            assert_eq!(ans, bytes);
+
+            let has_letter = hex.iter().any(u8::is_ascii_alphabetic);
+            assert_eq!(hex_simd::check_strict(&hex, other).is_ok(), !has_letter);
+
+            for i in 0..hex.len() {
+                for bad in [b'g', b'G', b'/', b':', b'@', b'`', 0x80, hex[i] ^ 0x20] {
+                    if bad == hex[i] {
+                        continue;
+                    }
+                    let mut src = hex.clone();
+                    src[i] = bad;
+                    assert!(hex_simd::check_strict(&src, case).is_err());
+                    let mut buf = vec![MaybeUninit::<u8>::uninit(); n];
+                    assert!(hex_simd::decode_strict(&src, buf.as_mut_slice().as_out(), case).is_err());
+                }
+            }
+        }
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn error_kind() {
+    use hex_simd::ErrorKind;
+
+    let invalid = |offset, byte| ErrorKind::InvalidCharacter { offset, byte };
+
+    for n in [1, 2, 15, 16, 17, 31, 32, 33, 63, 64, 65, 100] {
+        let hex = vec![b'a'; n * 2];
+        for i in 0..hex.len() {
+            let mut src = hex.clone();
+            src[i] = b'z';
+
+            let err = hex_simd::check(&src).unwrap_err();
+            assert_eq!(err.kind(), invalid(i, b'z'));
+            assert_eq!(err.offset(), Some(i));
+
+            let mut buf = vec![MaybeUninit::<u8>::uninit(); n];
+            let err = hex_simd::decode(&src, buf.as_mut_slice().as_out()).unwrap_err();
+            assert_eq!(err.kind(), invalid(i, b'z'));
+
+            let err = hex_simd::decode_strict(&src, buf.as_mut_slice().as_out(), AsciiCase::Lower).unwrap_err();
+            assert_eq!(err.kind(), invalid(i, b'z'));
+
+            #[cfg(feature = "alloc")]
+            assert_eq!(hex_simd::decode_to_vec(&src).unwrap_err().kind(), invalid(i, b'z'));
+        }
+    }
+
+    let mut buf = [MaybeUninit::<u8>::uninit(); 2];
+    let err = hex_simd::decode(b"abc", buf.as_mut_slice().as_out()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidLength);
+    assert_eq!(err.offset(), None);
+
+    let err = hex_simd::decode(b"abcdef", buf.as_mut_slice().as_out()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::BufferTooSmall);
+
+    let err = hex_simd::check_strict(b"abCD", AsciiCase::Lower).unwrap_err();
+    assert_eq!(err.kind(), invalid(2, b'C'));
+
+    let mut buf = [MaybeUninit::<u8>::uninit(); 3];
+    let err = hex_simd::decode_separated(b"aa:bb-cc", buf.as_mut_slice().as_out(), b':').unwrap_err();
+    assert_eq!(err.kind(), invalid(5, b'-'));
+
+    let options = hex_simd::DecodeOptions {
+        prefix: hex_simd::Prefix::Require0x,
+        odd: hex_simd::OddLength::LeadingZeroNibble,
+        ignore_whitespace: true,
+    };
+    let err = hex_simd::decode_with(b" 0x1 2 g", buf.as_mut_slice().as_out(), options).unwrap_err();
+    assert_eq!(err.kind(), invalid(7, b'g'));
+    let err = hex_simd::decode_with(b" 0y12", buf.as_mut_slice().as_out(), options).unwrap_err();
+    assert_eq!(err.kind(), invalid(2, b'y'));
+
+    #[cfg(feature = "alloc")]
+    {
+        let err = hex_simd::dump::undump(b"00000000: 4142  AB\n00000002: 43x4  C.\n").unwrap_err();
+        assert_eq!(err.kind(), invalid(31, b'x'));
+    }
+
+    #[cfg(feature = "std")]
+    {
+        use std::io::Read;
+
+        let mut src = vec![b'0'; 20000];
+        src[12345] = b'!';
+        let mut reader = hex_simd::io::DecoderReader::new(src.as_slice());
+        let err = reader.read_to_end(&mut Vec::new()).unwrap_err();
+        let err = err.into_inner().unwrap().downcast::<hex_simd::Error>().unwrap();
+        assert_eq!(err.kind(), invalid(12345, b'!'));
+    }
+
+    let err = hex_simd::decode(b"zz", buf.as_mut_slice().as_out()).unwrap_err();
+    assert_eq!(err.to_string(), "HexError: invalid character 0x7a at offset 0");
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn array() {
+    fn roundtrip<const N: usize, const M: usize>() {
+        let bytes: [u8; N] = rand_bytes(N).try_into().unwrap();
+        for case in [AsciiCase::Lower, AsciiCase::Upper] {
+            let hex: [u8; M] = hex_simd::encode_array(&bytes, case);
+            assert_eq!(hex.as_slice(), hex_simd::encode_to_string(bytes, case).as_bytes());
+
+            let ans: [u8; N] = hex_simd::decode_array(&hex).unwrap();
+            assert_eq!(ans, bytes);
+            let ans: [u8; N] = hex_simd::decode_type(hex).unwrap();
+            assert_eq!(ans, bytes);
+        }
+    }
+
+    roundtrip::<0, 0>();
+    roundtrip::<1, 2>();
+    roundtrip::<16, 32>();
+    roundtrip::<20, 40>();
+    roundtrip::<32, 64>();
+    roundtrip::<33, 66>();
+
+    let err = hex_simd::decode_array::<20>(b"00").unwrap_err();
+    assert_eq!(err.kind(), hex_simd::ErrorKind::InvalidLength);
+    let err = hex_simd::decode_array::<1>(b"000").unwrap_err();
+    assert_eq!(err.kind(), hex_simd::ErrorKind::InvalidLength);
+
+    let mut hex = [b'a'; 64];
+    hex[50] = b'x';
+    let err = hex_simd::decode_type::<[u8; 32]>(hex).unwrap_err();
+    assert_eq!(err.offset(), Some(50));
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn int() {
+    use hex_simd::int::*;
+    use hex_simd::ErrorKind;
+
+    let values: &[u64] = &[
+        0,
+        1,
+        0xf,
+        0x10,
+        0xbeef,
+        0xdead_beef,
+        0x1_0000_0000,
+        u64::MAX >> 4,
+        u64::MAX,
+    ];
+    for &x in values {
+        let lower = format!("{x:016x}");
+        let upper = format!("{x:016X}");
+        assert_eq!(format_u64(x, AsciiCase::Lower).as_slice(), lower.as_bytes());
+        assert_eq!(format_u64(x, AsciiCase::Upper).as_slice(), upper.as_bytes());
+
+        let mut buf = [0u8; 16];
+        let trimmed = format_u64_trimmed(x, AsciiCase::Lower, buf.as_out());
+        assert_eq!(trimmed, format!("{x:x}").as_bytes());
+
+        assert_eq!(parse_u64(lower.as_bytes()).unwrap(), x);
+        assert_eq!(parse_u64(upper.as_bytes()).unwrap(), x);
+        assert_eq!(parse_u64(format!("{x:X}").as_bytes()).unwrap(), x);
+
+        let y = x as u32;
+        assert_eq!(parse_u32(format!("{y:08x}").as_bytes()).unwrap(), y);
+        assert_eq!(parse_u32(format!("{y:x}").as_bytes()).unwrap(), y);
+
+        let z = u128::from(x) << 64 | u128::from(!x);
+        assert_eq!(parse_u128(format!("{z:032x}").as_bytes()).unwrap(), z);
+        assert_eq!(
+            parse_u128(format!("{:x}", u128::from(x)).as_bytes()).unwrap(),
+            u128::from(x)
+        );
+    }
+
+    assert_eq!(parse_u32(b"").unwrap_err().kind(), ErrorKind::InvalidLength);
+    assert_eq!(parse_u32(b"123456789").unwrap_err().kind(), ErrorKind::InvalidLength);
+    assert_eq!(parse_u64(&[b'0'; 17]).unwrap_err().kind(), ErrorKind::InvalidLength);
+    assert_eq!(parse_u128(&[b'0'; 33]).unwrap_err().kind(), ErrorKind::InvalidLength);
+
+    let invalid = |offset, byte| ErrorKind::InvalidCharacter { offset, byte };
+    assert_eq!(parse_u32(b"12g4").unwrap_err().kind(), invalid(2, b'g'));
+    assert_eq!(parse_u64(b"0123456789abcde-").unwrap_err().kind(), invalid(15, b'-'));
+    assert_eq!(
+        parse_u128(b"0123456789abcdef012345678 ").unwrap_err().kind(),
+        invalid(25, b' ')
+    );
+    assert_eq!(
+        parse_u128(b"x0123456789abcdef0123").unwrap_err().kind(),
+        invalid(0, b'x')
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn display() {
+    for n in [0, 1, 15, 16, 17, 255, 256, 257, 1000] {
+        let data = rand_bytes(n);
+        let lower = hex_simd::encode_to_string(&data, AsciiCase::Lower);
+        let upper = hex_simd::encode_to_string(&data, AsciiCase::Upper);
+        let hex = hex_simd::display(&data);
+
+        assert_eq!(format!("{hex}"), lower);
+        assert_eq!(format!("{hex:x}"), lower);
+        assert_eq!(format!("{hex:X}"), upper);
+        assert_eq!(format!("{hex:#x}"), format!("0x{lower}"));
+        assert_eq!(format!("{hex:#X}"), format!("0x{upper}"));
+
+        let width = n * 2 + 5;
+        assert_eq!(format!("{hex:width$}"), format!("{lower:width$}"));
+        assert_eq!(format!("{hex:>width$}"), format!("{lower:>width$}"));
+        assert_eq!(format!("{hex:-^width$X}"), format!("{upper:-^width$}"));
+        assert_eq!(format!("{hex:#0width$x}"), format!("0x{lower:0>w$}", w = width - 2));
+        assert_eq!(format!("{hex:3}"), format!("{lower:3}"));
+    }
+
+    let x = 0x00c0_ffee_u32;
+    let bytes = x.to_be_bytes();
+    let hex = hex_simd::display(&bytes);
+    assert_eq!(
+        format!("{hex:#012x}"),
+        format!("{x:#012x}").replace("0xc0ffee", "0x00c0ffee")
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn ihex() {
+    use hex_simd::ihex::{self, IntelHex, Segment};
+    use hex_simd::ErrorKind;
+
+    let text = "\
+        :10010000214601360121470136007EFE09D2190140\r\n\
+        :100110002146017E17C20001FF5F16002148011928\r\n\
+        :020000021000EC\r\n\
+        :0400000300003800C1\r\n\
+        :0300300002337A1E\r\n\
+        :04000005000000CD2A\r\n\
+        :00000001FF\r\n";
+    let file = ihex::parse(text.as_bytes()).unwrap();
+    assert_eq!(file.segments.len(), 2);
+    assert_eq!(file.segments[0].address, 0x0100);
+    assert_eq!(file.segments[0].data.len(), 32);
+    assert_eq!(file.segments[0].data[..4], [0x21, 0x46, 0x01, 0x36]);
+    assert_eq!(
+        file.segments[1],
+        Segment {
+            address: 0x10030,
+            data: vec![0x02, 0x33, 0x7a]
+        }
+    );
+    assert_eq!(file.start_segment_address, Some(0x0000_3800));
+    assert_eq!(file.start_linear_address, Some(0x0000_00cd));
+
+    let roundtrip = ihex::parse(ihex::write_to_string(&file, 16).as_bytes()).unwrap();
+    assert_eq!(roundtrip, file);
+
+    for len in [0, 1, 100, 1000] {
+        let file = IntelHex {
+            segments: vec![
+                Segment {
+                    address: 0x0000_fff0,
+                    data: rand_bytes(len),
+                },
+                Segment {
+                    address: 0x1234_5678,
+                    data: rand_bytes(len + 1),
+                },
+            ],
+            start_segment_address: None,
+            start_linear_address: Some(0x0800_0000),
+        };
+        for record_len in [1, 16, 32, 255] {
+            let text = ihex::write_to_string(&file, record_len);
+            assert!(text
+                .lines()
+                .all(|line| line.len() <= 11 + usize::from(record_len.max(4)) * 2));
+            let mut ans = ihex::parse(text.as_bytes()).unwrap();
+            ans.segments.retain(|seg| !seg.data.is_empty());
+            let mut expected = file.clone();
+            expected.segments.retain(|seg| !seg.data.is_empty());
+            assert_eq!(ans, expected);
+        }
+    }
+
+    let err = |text: &str| ihex::parse(text.as_bytes()).unwrap_err().kind();
+    assert_eq!(
+        err(":0100000000FF\n:00000001FE\n"),
+        ErrorKind::InvalidChecksum { offset: 14 }
+    );
+    assert_eq!(
+        err("\n:0000000G0F\n"),
+        ErrorKind::InvalidCharacter { offset: 9, byte: b'G' }
+    );
+    assert_eq!(
+        err("00000001FF\n"),
+        ErrorKind::InvalidCharacter { offset: 0, byte: b'0' }
+    );
+    assert_eq!(err(":01000001FF\n"), ErrorKind::InvalidLength);
+    assert_eq!(err(":0000000F1\n"), ErrorKind::InvalidLength);
+    assert_eq!(err(":00000006FA\n"), ErrorKind::InvalidRecord { offset: 0 });
+    assert_eq!(err(":0100000400FB\n"), ErrorKind::InvalidRecord { offset: 0 });
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn srec() {
+    use hex_simd::srec::{self, AddressWidth, SRecordFile, Segment};
+    use hex_simd::ErrorKind;
+
+    let text = "\
+        S00F000068656C6C6F202020202000003C\r\n\
+        S11F00007C0802A6900100049421FFF07C6C1B787C8C23783C6000003863000026\r\n\
+        S11F001C4BFFFFE5398000007D83637880010014382100107C0803A64E800020E9\r\n\
+        S111003848656C6C6F20776F726C642E0A0042\r\n\
+        S5030003F9\r\n\
+        S9030000FC\r\n";
+    let file = srec::parse(text.as_bytes()).unwrap();
+    assert_eq!(file.header, b"hello     \0\0");
+    assert_eq!(file.segments.len(), 1);
+    assert_eq!(file.segments[0].address, 0);
+    assert_eq!(file.segments[0].data.len(), 0x46);
+    assert!(file.segments[0].data.ends_with(b"Hello world.\n\0"));
+    assert_eq!(file.address_width, AddressWidth::Bits16);
+    assert_eq!(file.start_address, Some(0));
+
+    let written = srec::write_to_string(&file, 28);
+    assert_eq!(written, text.replace("\r\n", "\n"));
+    assert_eq!(srec::parse(written.as_bytes()).unwrap(), file);
+
+    for (width, address) in [
+        (AddressWidth::Bits16, 0xff00),
+        (AddressWidth::Bits24, 0x00ab_cd00),
+        (AddressWidth::Bits32, 0xfedc_ba00),
+    ] {
+        for len in [1, 100, 1000] {
+            let file = SRecordFile {
+                header: b"test".to_vec(),
+                segments: vec![Segment {
+                    address,
+                    data: rand_bytes(len.min(0x100)),
+                }],
+                address_width: width,
+                start_address: Some(address),
+            };
+            for record_len in [1, 16, 32, 255] {
+                let text = srec::write_to_string(&file, record_len);
+                assert_eq!(srec::parse(text.as_bytes()).unwrap(), file);
+            }
+        }
+    }
+
+    let err = |text: &str| srec::parse(text.as_bytes()).unwrap_err().kind();
+    assert_eq!(
+        err("S5030000FC\nS9030000FD\n"),
+        ErrorKind::InvalidChecksum { offset: 11 }
+    );
+    assert_eq!(
+        err("S5030000FC\nS9030000fz\n"),
+        ErrorKind::InvalidCharacter { offset: 20, byte: b'z' }
+    );
+    assert_eq!(
+        err("X9030000FC\n"),
+        ErrorKind::InvalidCharacter { offset: 0, byte: b'X' }
+    );
+    assert_eq!(
+        err("SX030000FC\n"),
+        ErrorKind::InvalidCharacter { offset: 1, byte: b'X' }
+    );
+    assert_eq!(err("S9040000FC\n"), ErrorKind::InvalidLength);
+    assert_eq!(err("S4030000FC\n"), ErrorKind::InvalidRecord { offset: 0 });
+    assert_eq!(err("S5030001FB\n"), ErrorKind::InvalidRecord { offset: 0 });
+    assert_eq!(err("S30200FD\n"), ErrorKind::InvalidRecord { offset: 0 });
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn parallel() {
+    for n in [0, 1, 100, 4096, 100_000, 1_000_001] {
+        let src = rand_bytes(n);
+        for case in [AsciiCase::Lower, AsciiCase::Upper] {
+            let encoded = hex_simd::par_encode_to_string(&src, case);
+            assert_eq!(encoded, hex_simd::encode_to_string(&src, case));
+
+            let mut buf = vec![0; n * 2];
+            assert_eq!(
+                hex_simd::par_encode(&src, buf.as_out(), case).unwrap(),
+                encoded.as_bytes()
+            );
+
+            hex_simd::par_check(encoded.as_bytes()).unwrap();
+            assert_eq!(hex_simd::par_decode_to_vec(&encoded).unwrap(), src);
+
+            let mut buf = vec![0; n];
+            assert_eq!(hex_simd::par_decode(encoded.as_bytes(), buf.as_out()).unwrap(), src);
+        }
+    }
+
+    let mut encoded = hex_simd::encode_to_string(rand_bytes(1_000_000), AsciiCase::Lower).into_bytes();
+    encoded[1_234_567] = b'g';
+    let err = hex_simd::par_decode_to_vec(&encoded).unwrap_err();
+    assert_eq!(err.offset(), Some(1_234_567));
+    assert_eq!(hex_simd::par_check(&encoded).unwrap_err().offset(), Some(1_234_567));
+
+    let err = hex_simd::par_decode_to_vec(&encoded[1..]).unwrap_err();
+    assert_eq!(err.kind(), hex_simd::ErrorKind::InvalidLength);
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn words() {
+    use hex_simd::{Endian, Word};
+    use std::fmt::LowerHex;
+
+    fn check<T: Word + LowerHex + Default + PartialEq + std::fmt::Debug>(words: &[T], to_le: fn(T) -> Vec<u8>) {
+        let width = std::mem::size_of::<T>() * 2;
+        let big: String = words.iter().map(|x| format!("{x:0width$x}")).collect();
+        let little: String = words
+            .iter()
+            .flat_map(|&x| to_le(x))
+            .map(|b| format!("{b:02x}"))
+            .collect();
+
+        for (endian, expected) in [(Endian::Big, &big), (Endian::Little, &little)] {
+            let mut buf = vec![0u8; expected.len()];
+            let ans = hex_simd::encode_words(words, buf.as_mut_slice().as_out(), AsciiCase::Lower, endian).unwrap();
+            assert_eq!(ans, expected.as_bytes());
+
+            let upper = expected.to_ascii_uppercase();
+            let mut buf = vec![T::default(); words.len()];
+            let ans = hex_simd::decode_words(upper.as_bytes(), buf.as_mut_slice().as_out(), endian).unwrap();
+            assert_eq!(ans, words);
+        }
+    }
+
+    for n in [0, 1, 3, 7, 8, 9, 16, 17, 33, 100] {
+        let bytes = rand_bytes(n * 8);
+        let u16s: Vec<u16> = bytes.chunks(2).map(|c| u16::from_ne_bytes([c[0], c[1]])).collect();
+        let u32s: Vec<u32> = bytes
+            .chunks(4)
+            .map(|c| u32::from_ne_bytes(c.try_into().unwrap()))
+            .collect();
+        let u64s: Vec<u64> = bytes
+            .chunks(8)
+            .map(|c| u64::from_ne_bytes(c.try_into().unwrap()))
+            .collect();
+        check(&u16s, |x| x.to_le_bytes().to_vec());
+        check(&u32s, |x| x.to_le_bytes().to_vec());
+        check(&u64s, |x| x.to_le_bytes().to_vec());
+    }
+
+    let mut buf = [0u32; 4];
+    let err = hex_simd::decode_words(b"0011223", buf.as_mut_slice().as_out(), Endian::Big).unwrap_err();
+    assert_eq!(err.kind(), hex_simd::ErrorKind::InvalidLength);
+    let err = hex_simd::decode_words(b"0011223x", buf.as_mut_slice().as_out(), Endian::Big).unwrap_err();
+    assert_eq!(err.offset(), Some(7));
+    let mut buf = [0u32; 1];
+    let err = hex_simd::decode_words(b"0011223344556677", buf.as_mut_slice().as_out(), Endian::Little).unwrap_err();
+    assert_eq!(err.kind(), hex_simd::ErrorKind::BufferTooSmall);
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn serde() {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Fields {
+        #[serde(with = "hex_simd::serde::lower")]
+        vec: Vec<u8>,
+        #[serde(with = "hex_simd::serde::upper")]
+        boxed: Box<[u8]>,
+        #[serde(with = "hex_simd::serde::prefixed_lower")]
+        array: [u8; 4],
+        #[serde(with = "hex_simd::serde::prefixed_upper")]
+        empty: Vec<u8>,
+    }
+
+    let fields = Fields {
+        vec: vec![0x01, 0xab],
+        boxed: vec![0xcd, 0xef].into_boxed_slice(),
+        array: [0xde, 0xad, 0xbe, 0xef],
+        empty: Vec::new(),
+    };
+    let json = serde_json::to_string(&fields).unwrap();
+    assert_eq!(
+        json,
+        r#"{"vec":"01ab","boxed":"CDEF","array":"0xdeadbeef","empty":"0x"}"#
+    );
+    assert_eq!(serde_json::from_str::<Fields>(&json).unwrap(), fields);
+
+    let json = r#"{"vec":"01AB","boxed":"cdEF","array":"0XDEADbeef","empty":""}"#;
+    assert_eq!(serde_json::from_str::<Fields>(json).unwrap(), fields);
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Plain(#[serde(with = "hex_simd::serde::lower")] Vec<u8>);
+    #[derive(Debug, Deserialize)]
+    struct Fixed(#[serde(with = "hex_simd::serde::prefixed_lower")] [u8; 2]);
+
+    let long = rand_bytes(1000);
+    let json = serde_json::to_string(&Plain(long.clone())).unwrap();
+    assert_eq!(
+        json[1..json.len() - 1],
+        hex_simd::encode_to_string(&long, AsciiCase::Lower)
+    );
+    assert_eq!(serde_json::from_str::<Plain>(&json).unwrap().0, long);
+
+    assert!(serde_json::from_str::<Plain>(r#""0x01""#).is_err());
+    assert!(serde_json::from_str::<Plain>(r#""012""#).is_err());
+    assert!(serde_json::from_str::<Plain>(r#""0g""#).is_err());
+    assert!(serde_json::from_str::<Plain>("[1, 2]").is_err());
+    assert!(serde_json::from_str::<Fixed>(r#""0x010203""#).is_err());
+    assert!(serde_json::from_str::<Fixed>(r#""0x0x0102""#).is_err());
+    assert_eq!(serde_json::from_str::<Fixed>(r#""0x0102""#).unwrap().0, [1, 2]);
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn forgiving() {
+    fn check(src: &[u8], expected: &[u8]) {
+        let mut buf = vec![0; expected.len()];
+        let ans = hex_simd::forgiving_decode(src, buf.as_mut_slice().as_out()).unwrap();
+        assert_eq!(ans, expected);
+
+        let ans = hex_simd::forgiving_decode_to_vec(src).unwrap();
+        assert_eq!(ans, expected);
+
+        let mut buf = src.to_vec();
+        let ans = hex_simd::forgiving_decode_inplace(&mut buf).unwrap();
+        assert_eq!(ans, expected);
+    }
+
+    check(b"", b"");
+    check(b" \t\r\n\x0c", b"");
+    check(b"0123abCD", &[0x01, 0x23, 0xab, 0xcd]);
+    check(b"01 23\tab\r\ncd\n", &[0x01, 0x23, 0xab, 0xcd]);
+    check(b"0 1 2 3", &[0x01, 0x23]);
+    check(b"  0123", &[0x01, 0x23]);
+
+    for n in [1, 16, 100, 1000] {
+        let bytes = rand_bytes(n);
+        let hex = hex_simd::encode_to_string(&bytes, AsciiCase::Upper);
+        for sep in [" ", "\n", " \r\n\t"] {
+            let lines: Vec<&str> = hex
+                .as_bytes()
+                .chunks(5)
+                .map(|c| core::str::from_utf8(c).unwrap())
+                .collect();
+            check(lines.join(sep).as_bytes(), &bytes);
+        }
+    }
+
+    let mut buf = [0u8; 4];
+    let err = |src: &[u8], dst: &mut [u8]| hex_simd::forgiving_decode(src, dst.as_out()).unwrap_err();
+
+    let e = err(b"01 2", &mut buf);
+    assert_eq!(e.kind(), hex_simd::ErrorKind::InvalidLength);
+    let e = err(b"01 23 4g", &mut buf);
+    assert_eq!(
+        e.kind(),
+        hex_simd::ErrorKind::InvalidCharacter { offset: 7, byte: b'g' }
+    );
+    let e = err(b"0x 01", &mut buf);
+    assert_eq!(
+        e.kind(),
+        hex_simd::ErrorKind::InvalidCharacter { offset: 1, byte: b'x' }
+    );
+    let e = err(b"01 23 45", &mut buf[..2]);
+    assert_eq!(e.kind(), hex_simd::ErrorKind::BufferTooSmall);
+
+    let long = format!("{} zz", "00 ".repeat(1000));
+    let e = hex_simd::forgiving_decode_to_vec(long.as_bytes()).unwrap_err();
+    assert_eq!(e.offset(), Some(3001));
+
+    let mut buf = b"01 2".to_vec();
+    assert!(hex_simd::forgiving_decode_inplace(&mut buf).is_err());
+    let mut buf = b"01 2g".to_vec();
+    assert!(hex_simd::forgiving_decode_inplace(&mut buf).is_err());
+}
@@ -1,26 +1,102 @@
 use core::fmt;
 
 /// UUID Error
-pub struct Error(());
+pub struct Error {
+    kind: ErrorKind,
+}
+
+/// The kind of a UUID error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// An invalid character is found in the input.
+    InvalidCharacter {
+        /// The offset of the character in the input.
+        offset: usize,
+        /// The value of the character.
+        byte: u8,
+    },
+    /// The length of the input doesn't match any UUID format.
+    InvalidLength,
+    /// A hyphen is missing or found at a wrong position.
+    MisplacedHyphen {
+        /// The offset of the character in the input.
+        offset: usize,
+    },
+    /// The input is invalid but the position of the error is unknown.
+    Invalid,
+}
 
 impl Error {
     #[inline(always)]
     pub(crate) const fn new() -> Self {
-        Error(())
+        Self::from_kind(ErrorKind::Invalid)
+    }
+
+    #[inline(always)]
+    pub(crate) const fn from_kind(kind: ErrorKind) -> Self {
+        Error { kind }
     }
+
+    /// Returns the kind of this error.
+    #[inline]
+    #[must_use]
+    pub const fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Returns the offset of the error in the input, if known.
+    #[inline]
+    #[must_use]
+    pub const fn offset(&self) -> Option<usize> {
+        match self.kind {
+            ErrorKind::InvalidCharacter { offset, .. } | ErrorKind::MisplacedHyphen { offset } => Some(offset),
+            _ => None,
+        }
+    }
+}
+
+/// Finds the first invalid character of a simple or hyphenated UUID which starts at `base`.
+///
+/// The fast paths only report that the input is invalid.
+/// This function is called after they fail so that the happy path does not pay for the position.
+#[cold]
+#[inline(never)]
+pub(crate) fn locate(src: &[u8], base: usize, hyphenated: bool) -> Error {
+    let is_hyphen_pos = |i: usize| hyphenated && matches!(i, 8 | 13 | 18 | 23);
+    for (i, &byte) in src[base..].iter().enumerate() {
+        let offset = base + i;
+        if is_hyphen_pos(i) {
+            if byte != b'-' {
+                return Error::from_kind(ErrorKind::MisplacedHyphen { offset });
+            }
+        } else if byte == b'-' {
+            return Error::from_kind(ErrorKind::MisplacedHyphen { offset });
+        } else if vsimd::hex::unhex(byte) == 0xff {
+            return Error::from_kind(ErrorKind::InvalidCharacter { offset, byte });
+        }
+    }
+    Error::new()
 }
 
 impl fmt::Debug for Error {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        <str as fmt::Debug>::fmt("UUIDError", f)
+        f.debug_tuple("UUIDError").field(&self.kind).finish()
     }
 }
 
 impl fmt::Display for Error {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        <str as fmt::Display>::fmt("UUIDError", f)
+        match self.kind {
+            ErrorKind::InvalidCharacter { offset, byte } => {
+                write!(f, "UUIDError: invalid character {byte:#04x} at offset {offset}")
+            }
+            ErrorKind::InvalidLength => f.write_str("UUIDError: invalid length"),
+            ErrorKind::MisplacedHyphen { offset } => write!(f, "UUIDError: misplaced hyphen at offset {offset}"),
+            ErrorKind::Invalid => f.write_str("UUIDError"),
+        }
     }
 }
 
@@ -34,6 +110,11 @@ macro_rules! ensure {
             return Err($crate::error::Error::new());
         }
     };
+    ($cond:expr, $kind:ident) => {
+        if !$cond {
+            return Err($crate::error::Error::from_kind($crate::error::ErrorKind::$kind));
+        }
+    };
 }
 
 #[allow(unused_macros)]
@@ -43,7 +43,8 @@
 
 #[macro_use]
 mod error;
-pub use self::error::Error;
+use self::error::locate;
+pub use self::error::{Error, ErrorKind};
 
 mod spec;
 
@@ -63,8 +64,6 @@ pub use vsimd::ascii::AsciiCase;
 
 // -------------------------------------------------------------------------------------------------
 
-use vsimd::tools::read;
-
 /// Parses an UUID from arbitrary bytes.
 ///
 /// # Errors
@@ -78,32 +77,52 @@ pub fn parse<'d>(src: &[u8], mut dst: Out<'d, [u8; 16]>) -> Result<&'d mut [u8;
 
     if n == 32 {
         unsafe {
-            let src = src.as_ptr();
             let dst = dst.as_mut_ptr().cast::<u8>();
-            crate::multiversion::parse_simple::auto(src, dst)?;
+            crate::multiversion::parse_simple::auto(src.as_ptr(), dst).map_err(|_| locate(src, 0, false))?;
             return Ok(&mut *dst.cast());
         }
     }
 
-    unsafe {
-        let src = match n {
-            36 => src.as_ptr(),
-            // Microsoft GUID
-            38 => {
-                let src = src.as_ptr();
-                ensure!(read(src, 0) == b'{' && read(src, 37) == b'}');
-                src.add(1)
+    let base = match n {
+        36 => 0,
+        // Microsoft GUID
+        38 => {
+            if src[0] != b'{' {
+                return Err(invalid_character(src, 0));
+            }
+            if src[37] != b'}' {
+                return Err(invalid_character(src, 37));
             }
-            // URN prefixed UUID
-            45 => src.strip_prefix(b"urn:uuid:").ok_or_else(Error::new)?.as_ptr(),
-            _ => return Err(Error::new()),
-        };
+            1
+        }
+        // URN prefixed UUID
+        45 => {
+            const PREFIX: &[u8] = b"urn:uuid:";
+            if let Some(i) = (0..PREFIX.len()).find(|&i| src[i] != PREFIX[i]) {
+                return Err(invalid_character(src, i));
+            }
+            PREFIX.len()
+        }
+        _ => return Err(Error::from_kind(ErrorKind::InvalidLength)),
+    };
+
+    unsafe {
         let dst = dst.as_mut_ptr().cast::<u8>();
-        crate::multiversion::parse_hyphenated::auto(src, dst)?;
+        crate::multiversion::parse_hyphenated::auto(src.as_ptr().add(base), dst)
+            .map_err(|_| locate(&src[..base + 36], base, true))?;
         Ok(&mut *dst.cast())
     }
 }
 
+#[cold]
+#[inline(never)]
+fn invalid_character(src: &[u8], offset: usize) -> Error {
+    Error::from_kind(ErrorKind::InvalidCharacter {
+        offset,
+        byte: src[offset],
+    })
+}
+
 /// Parses a simple UUID from arbitrary bytes.
 ///
 /// # Errors
@@ -113,11 +132,10 @@ pub fn parse<'d>(src: &[u8], mut dst: Out<'d, [u8; 16]>) -> Result<&'d mut [u8;
 /// + The content of `src` is invalid.
 #[inline]
 pub fn parse_simple<'d>(src: &[u8], mut dst: Out<'d, [u8; 16]>) -> Result<&'d mut [u8; 16], Error> {
-    ensure!(src.len() == 32);
+    ensure!(src.len() == 32, InvalidLength);
     unsafe {
-        let src = src.as_ptr();
         let dst = dst.as_mut_ptr().cast::<u8>();
-        crate::multiversion::parse_simple::auto(src, dst)?;
+        crate::multiversion::parse_simple::auto(src.as_ptr(), dst).map_err(|_| locate(src, 0, false))?;
         Ok(&mut *dst.cast())
     }
 }
@@ -131,11 +149,10 @@ pub fn parse_simple<'d>(src: &[u8], mut dst: Out<'d, [u8; 16]>) -> Result<&'d mu
 /// + The content of `src` is invalid.
 #[inline]
 pub fn parse_hyphenated<'d>(src: &[u8], mut dst: Out<'d, [u8; 16]>) -> Result<&'d mut [u8; 16], Error> {
-    ensure!(src.len() == 36);
+    ensure!(src.len() == 36, InvalidLength);
     unsafe {
-        let src = src.as_ptr();
         let dst = dst.as_mut_ptr().cast::<u8>();
-        crate::multiversion::parse_hyphenated::auto(src, dst)?;
+        crate::multiversion::parse_hyphenated::auto(src.as_ptr(), dst).map_err(|_| locate(src, 0, true))?;
         Ok(&mut *dst.cast())
     }
 }
@@ -1,4 +1,4 @@
-use uuid_simd::{AsOut, AsciiCase};
+use uuid_simd::{AsOut, AsciiCase, ErrorKind};
 
 fn ok_cases() -> &'static [(&'static str, &'static str)] {
     const A1: &str = "67e5504410b1426f9247bb680e5fe0c8";
@@ -109,3 +109,52 @@ fn basic() {
         assert_eq!(output.as_slice(), expected.to_ascii_lowercase().as_bytes());
     }
 }
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn error_kind() {
+    let cases: &[(&str, ErrorKind)] = &[
+        ("67e5504410b1426f9247bb680e5fe0c", ErrorKind::InvalidLength),
+        (
+            "67e5504410b1426f9247bb680e5fe0cz",
+            ErrorKind::InvalidCharacter { offset: 31, byte: b'z' },
+        ),
+        (
+            "67e55044-10b1-426f-9247-bb680e5fe0c-",
+            ErrorKind::MisplacedHyphen { offset: 35 },
+        ),
+        (
+            "67e55044-10b1-426f-9247_bb680e5fe0c8",
+            ErrorKind::MisplacedHyphen { offset: 23 },
+        ),
+        (
+            "{67e55044-10b1-426f-9g47-bb680e5fe0c8}",
+            ErrorKind::InvalidCharacter { offset: 21, byte: b'g' },
+        ),
+        (
+            "{67e55044-10b1-426f-9247-bb680e5fe0c8)",
+            ErrorKind::InvalidCharacter { offset: 37, byte: b')' },
+        ),
+        (
+            "urn:uuid:67e55044-10b1-426f-9247bbb680e5fe0c8",
+            ErrorKind::MisplacedHyphen { offset: 32 },
+        ),
+        (
+            "urn:uuiD:67e55044-10b1-426f-9247-bb680e5fe0c8",
+            ErrorKind::InvalidCharacter { offset: 7, byte: b'D' },
+        ),
+    ];
+
+    for &(input, kind) in cases {
+        let mut buf = [0; 16];
+        let err = uuid_simd::parse(input.as_bytes(), buf.as_out()).unwrap_err();
+        assert_eq!(err.kind(), kind, "input = {input:?}");
+    }
+
+    let mut buf = [0; 16];
+    let err = uuid_simd::parse_hyphenated(b"67e5504410b1426f9247bb680e5fe0c8", buf.as_out()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidLength);
+    let err = uuid_simd::parse_simple(b"67e55044-10b1-426f-9247-bb680e5f", buf.as_out()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::MisplacedHyphen { offset: 8 });
+    assert_eq!(err.offset(), Some(8));
+}
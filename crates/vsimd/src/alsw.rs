@@ -50,11 +50,16 @@ impl AlswLut<V128> {
 }
 
 #[inline(always)]
-pub fn check_ascii_xn<S: Scalable<V>, V: POD>(s: S, x: V, check: AlswLut<V>) -> bool {
+pub fn classify_ascii_xn<S: Scalable<V>, V: POD>(s: S, x: V, check: AlswLut<V>) -> V {
     let shr3 = s.u32xn_shr::<3>(x);
     let h1 = s.u8xn_avgr(shr3, u8x16xn_lookup(s, check.hash, x));
     let o1 = u8x16xn_lookup(s, check.offset, h1);
-    let c1 = s.i8xn_add_sat(x, o1);
+    s.i8xn_add_sat(x, o1)
+}
+
+#[inline(always)]
+pub fn check_ascii_xn<S: Scalable<V>, V: POD>(s: S, x: V, check: AlswLut<V>) -> bool {
+    let c1 = classify_ascii_xn(s, x, check);
     s.u8xn_highbit_any(c1).not()
 }
 
@@ -1,5 +1,9 @@
+use crate::isa::AVX2;
 use crate::pod::POD;
-use crate::Scalable;
+use crate::vector::{V128, V256};
+use crate::{Scalable, SIMD256};
+
+use core::ops::Not;
 
 /// An enum type which represents the case of ascii letters.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -29,6 +33,277 @@ pub fn to_ascii_uppercase<S: Scalable<V>, V: POD>(s: S, x: V) -> V {
     convert_ascii_case::<S, V, b'a'>(s, x)
 }
 
+/// Returns whether `c` is an ascii whitespace or `EXTRA`.
+///
+/// Pass a whitespace as `EXTRA`, such as `b' '`, to match ascii whitespaces only.
+#[inline(always)]
+#[must_use]
+pub fn is_separator<const EXTRA: u8>(c: u8) -> bool {
+    lookup_ascii_whitespace(c) != 0 || (!EXTRA.is_ascii_whitespace() && c == EXTRA)
+}
+
+#[inline(always)]
+#[must_use]
+fn lookup_ascii_whitespace(c: u8) -> u8 {
+    const TABLE: &[u8; 256] = &{
+        let mut ans = [0; 256];
+        let mut i: u8 = 0;
+        loop {
+            ans[i as usize] = if i.is_ascii_whitespace() { 0xff } else { 0 };
+            if i == 255 {
+                break;
+            }
+            i += 1;
+        }
+        ans
+    };
+    unsafe { *TABLE.get_unchecked(c as usize) }
+}
+
+#[inline(always)]
+fn has_separator<S: Scalable<V>, V: POD, const EXTRA: u8>(s: S, x: V) -> bool {
+    // ASCII whitespaces
+    // TAB      0x09    00001001
+    // LF       0x0a    00001010
+    // FF       0x0c    00001100
+    // CR       0x0d    00001101
+    // SPACE    0x20    00100000
+    //
+
+    // m1 = {{byte in 0x09..=0x0d}}
+    let m1 = s.i8xn_lt(s.u8xn_sub(x, s.u8xn_splat(0x89)), s.i8xn_splat(-128 + 5));
+
+    // m2 = {{byte == 0x0b}}
+    let m2 = s.u8xn_eq(x, s.u8xn_splat(0x0b));
+
+    // m3 = {{byte is SPACE}}
+    let m3 = s.u8xn_eq(x, s.u8xn_splat(0x20));
+
+    // m = (m1 & !m2) | m3
+    let m = s.or(s.andnot(m1, m2), m3);
+
+    if EXTRA.is_ascii_whitespace() {
+        return s.mask8xn_any(m);
+    }
+
+    // m4 = {{byte is EXTRA}}
+    let m4 = s.u8xn_eq(x, s.u8xn_splat(EXTRA));
+
+    // any(m | m4)
+    s.mask8xn_any(s.or(m, m4))
+}
+
+/// Returns the position of the first separator in `src[..len]`, or `len` if there is none.
+///
+/// See [`is_separator`].
+///
+/// # Safety
+/// `src[..len]` must be readable.
+#[inline(always)]
+#[must_use]
+pub unsafe fn find_separator_fallback<const EXTRA: u8>(mut src: *const u8, len: usize) -> usize {
+    let base = src;
+    let end = base.add(len);
+    while src < end {
+        if is_separator::<EXTRA>(src.read()) {
+            break;
+        }
+        src = src.add(1);
+    }
+
+    src.offset_from(base) as usize
+}
+
+/// SIMD version of [`find_separator_fallback`].
+///
+/// # Safety
+/// `src[..len]` must be readable.
+#[inline(always)]
+#[must_use]
+pub unsafe fn find_separator_simd<S: SIMD256, const EXTRA: u8>(s: S, mut src: *const u8, len: usize) -> usize {
+    let base = src;
+
+    if matches_isa!(S, AVX2) {
+        let end = src.add(len / 32 * 32);
+        while src < end {
+            let x = s.v256_load_unaligned(src);
+            if has_separator::<S, V256, EXTRA>(s, x) {
+                break;
+            }
+            src = src.add(32);
+        }
+        if (len % 32) >= 16 {
+            let x = s.v128_load_unaligned(src);
+            if has_separator::<S, V128, EXTRA>(s, x).not() {
+                src = src.add(16);
+            }
+        }
+    } else {
+        let end = src.add(len / 16 * 16);
+        while src < end {
+            let x = s.v128_load_unaligned(src);
+            if has_separator::<S, V128, EXTRA>(s, x) {
+                break;
+            }
+            src = src.add(16);
+        }
+    }
+
+    let checked_len = src.offset_from(base) as usize;
+    let pos = find_separator_fallback::<EXTRA>(src, len - checked_len);
+    checked_len + pos
+}
+
+/// Copies the non-separator bytes of `src[..len]` to `dst` and returns the number of copied bytes.
+///
+/// # Safety
+/// `src[..len]` must be readable and `dst[..len]` must be writable.
+/// `dst` may be equal to `src`.
+#[inline(always)]
+#[must_use]
+pub unsafe fn remove_separators_fallback<const EXTRA: u8>(mut src: *const u8, len: usize, mut dst: *mut u8) -> usize {
+    let dst_base = dst;
+
+    let end = src.add(len);
+    while src < end {
+        let x = src.read();
+        if !is_separator::<EXTRA>(x) {
+            dst.write(x);
+            dst = dst.add(1);
+        }
+        src = src.add(1);
+    }
+
+    dst.offset_from(dst_base) as usize
+}
+
+/// Removes separators from `data` inplace.
+///
+/// `pos` is the position of the first separator, as returned by [`find_separator_simd`].
+///
+/// # Panics
+/// This function panics if `pos > data.len()`.
+#[inline(always)]
+#[must_use]
+pub fn remove_separators_inplace<const EXTRA: u8>(data: &mut [u8], pos: usize) -> &mut [u8] {
+    assert!(pos <= data.len());
+
+    if pos == data.len() {
+        return data;
+    }
+
+    unsafe {
+        let len = data.len() - pos;
+        let dst = data.as_mut_ptr().add(pos);
+        let src = dst;
+
+        let rem = remove_separators_fallback::<EXTRA>(src, len, dst);
+        debug_assert!(rem <= len);
+
+        data.get_unchecked_mut(..(pos + rem))
+    }
+}
+
+/// [`find_separator_fallback`] for ascii whitespaces.
+///
+/// # Safety
+/// `src[..len]` must be readable.
+#[inline(always)]
+#[must_use]
+pub unsafe fn find_non_ascii_whitespace_fallback(src: *const u8, len: usize) -> usize {
+    find_separator_fallback::<b' '>(src, len)
+}
+
+/// [`find_separator_simd`] for ascii whitespaces.
+///
+/// # Safety
+/// `src[..len]` must be readable.
+#[inline(always)]
+#[must_use]
+pub unsafe fn find_non_ascii_whitespace_simd<S: SIMD256>(s: S, src: *const u8, len: usize) -> usize {
+    find_separator_simd::<S, b' '>(s, src, len)
+}
+
+/// [`remove_separators_fallback`] for ascii whitespaces.
+///
+/// # Safety
+/// `src[..len]` must be readable and `dst[..len]` must be writable.
+/// `dst` may be equal to `src`.
+#[inline(always)]
+#[must_use]
+pub unsafe fn remove_ascii_whitespace_fallback(src: *const u8, len: usize, dst: *mut u8) -> usize {
+    remove_separators_fallback::<b' '>(src, len, dst)
+}
+
+/// [`remove_separators_inplace`] for ascii whitespaces.
+///
+/// # Panics
+/// This function panics if `pos > data.len()`.
+#[inline(always)]
+#[must_use]
+pub fn remove_ascii_whitespace_inplace(data: &mut [u8], pos: usize) -> &mut [u8] {
+    remove_separators_inplace::<b' '>(data, pos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn test_remove_ascii_whitespace() {
+        let cases = [
+            "\0\0\0\0",
+            "abcd",
+            "ab\tcd",
+            "ab\ncd",
+            "ab\x0Ccd",
+            "ab\rcd",
+            "ab cd",
+            "ab\t\n\x0C\r cd",
+            "ab\t\n\x0C\r =\t\n\x0C\r =\t\n\x0C\r ",
+        ];
+
+        let check = |case: &str, repeat: usize| {
+            let mut buf = case.repeat(repeat).into_bytes();
+            let expected = {
+                let mut v = buf.clone();
+                v.retain(|c| !c.is_ascii_whitespace());
+                v
+            };
+            let pos = unsafe { find_non_ascii_whitespace_fallback(buf.as_ptr(), buf.len()) };
+            let ans = remove_ascii_whitespace_inplace(&mut buf, pos);
+            assert_eq!(ans, &*expected, "case = {case:?}");
+        };
+
+        for case in cases {
+            check(case, 1);
+
+            if cfg!(not(miri)) {
+                check(case, 10);
+            }
+        }
+    }
+
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn test_remove_separators() {
+        let cases = ["abcd", "ab-cd", "-ab\t-\ncd-", "ab\t\n\x0C\r -=\t\n\x0C\r -="];
+
+        for case in cases {
+            let mut buf = case.repeat(10).into_bytes();
+            let expected = {
+                let mut v = buf.clone();
+                v.retain(|&c| !(c.is_ascii_whitespace() || c == b'-'));
+                v
+            };
+            let pos = unsafe { find_separator_fallback::<b'-'>(buf.as_ptr(), buf.len()) };
+            let ans = remove_separators_inplace::<b'-'>(&mut buf, pos);
+            assert_eq!(ans, &*expected, "case = {case:?}");
+        }
+    }
+}
+
 #[cfg(test)]
 mod algorithm {
     use crate::algorithm::*;
@@ -53,4 +328,16 @@ mod algorithm {
             assert_eq!(to_lower(c), c.to_ascii_lowercase());
         }
     }
+
+    #[test]
+    #[ignore]
+    fn is_ascii_whitespace() {
+        for x in 0..=255u8 {
+            let m1 = (x.wrapping_sub(0x89) as i8) < (-128 + 5);
+            let m2 = x == 0x0b;
+            let m3 = x == 0x20;
+            let ans = (m1 && !m2) || m3;
+            assert_eq!(ans, x.is_ascii_whitespace());
+        }
+    }
 }
@@ -1,4 +1,5 @@
 use crate::alsw::{self, AlswLut};
+use crate::ascii::AsciiCase;
 use crate::isa::{AVX2, NEON, SSSE3, WASM128};
 use crate::mask::{u8x16_highbit_any, u8x32_highbit_any};
 use crate::pod::POD;
@@ -45,12 +46,34 @@ where
     s.mask8xn_all(s.or(m1, m2))
 }
 
+#[inline(always)]
+pub fn check_case_xn<S, V>(s: S, x: V, case: AsciiCase) -> bool
+where
+    S: Scalable<V>,
+    V: POD,
+{
+    let letter = match case {
+        AsciiCase::Lower => 0x61,
+        AsciiCase::Upper => 0x41,
+    };
+    let x1 = s.u8xn_sub(x, s.u8xn_splat(0x30 + 0x80));
+    let x2 = s.u8xn_sub(x, s.u8xn_splat(letter + 0x80));
+    let m1 = s.i8xn_lt(x1, s.i8xn_splat(-118));
+    let m2 = s.i8xn_lt(x2, s.i8xn_splat(-122));
+    s.mask8xn_all(s.or(m1, m2))
+}
+
 pub const ENCODE_UPPER_LUT: V256 = V256::double_bytes(*UPPER_CHARSET);
 pub const ENCODE_LOWER_LUT: V256 = V256::double_bytes(*LOWER_CHARSET);
 
 #[inline(always)]
 pub fn encode_bytes16<S: SIMD256>(s: S, x: V128, lut: V256) -> V256 {
-    let x = s.u16x16_from_u8x16(x);
+    encode_u16x16(s, s.u16x16_from_u8x16(x), lut)
+}
+
+/// Encodes 16 bytes which are zero-extended to `u16x16`.
+#[inline(always)]
+pub fn encode_u16x16<S: SIMD256>(s: S, x: V256, lut: V256) -> V256 {
     let hi = s.u16x16_shl::<8>(x);
     let lo = s.u16x16_shr::<4>(x);
     let values = s.v256_and(s.v256_or(hi, lo), s.u8x32_splat(0x0f));